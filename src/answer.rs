@@ -0,0 +1,236 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+///
+/// Integers are stored as `Int` whenever they fit in an `i128`; only `u128` values above
+/// `i128::MAX` use `UInt`. Comparison and hashing go by numeric value either way. The
+/// [`Display`] form is the canonical text form and parses back with [`FromStr`], which is how
+/// the answers file, the `.expected` sidecars and the submit ledger store answers. Text is
+/// written as it is, so text such as `0042` or `none` reads back as another answer; see
+/// [`Answer::round_trips`].
+#[derive(Debug, Clone)]
+pub enum Answer {
+  Int(i128),
+  UInt(u128),
+  Text(String),
+  /// The puzzle has no answer for this input (e.g. no path exists), or no puzzle at all.
+  None,
+}
+
+impl Answer {
+  pub fn is_none(&self) -> bool {
+    matches!(self, Answer::None)
+  }
+
+  /// Whether the [`Display`] form parses back to this answer when stored on a line of its own.
+  ///
+  /// Only text can fail: text that reads as a number or `none`, is empty, has whitespace at
+  /// either end or spans lines. Such text must not be recorded.
+  pub fn round_trips(&self) -> bool {
+    match self {
+      Answer::Text(text) => {
+        !text.is_empty()
+          && !text.contains(['\n', '\r'])
+          && text.parse::<Answer>().is_ok_and(|parsed| parsed == *self)
+      }
+      _ => true,
+    }
+  }
+
+  /// Numeric value as sign and magnitude, which covers both `i128` and `u128` exactly.
+  fn as_number(&self) -> Option<(bool, u128)> {
+    match *self {
+      Answer::Int(n) => Some((n < 0, n.unsigned_abs())),
+      Answer::UInt(n) => Some((false, n)),
+      _ => None,
+    }
+  }
+}
+
+impl PartialEq for Answer {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Answer::Text(a), Answer::Text(b)) => a == b,
+      (Answer::None, Answer::None) => true,
+      _ => self.as_number().is_some() && self.as_number() == other.as_number(),
+    }
+  }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    match self {
+      Answer::Int(_) | Answer::UInt(_) => {
+        0u8.hash(state);
+        self.as_number().hash(state);
+      }
+      Answer::Text(text) => {
+        1u8.hash(state);
+        text.hash(state);
+      }
+      Answer::None => 2u8.hash(state),
+    }
+  }
+}
+
+impl PartialOrd for Answer {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// Numbers order numerically and before text; `None` sorts last.
+impl Ord for Answer {
+  fn cmp(&self, other: &Self) -> Ordering {
+    fn rank(answer: &Answer) -> u8 {
+      match answer {
+        Answer::Int(_) | Answer::UInt(_) => 0,
+        Answer::Text(_) => 1,
+        Answer::None => 2,
+      }
+    }
+
+    match (self.as_number(), other.as_number()) {
+      (Some((a_neg, a)), Some((b_neg, b))) => match (a_neg, b_neg) {
+        (false, false) => a.cmp(&b),
+        (true, true) => b.cmp(&a),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+      },
+      _ => match (self, other) {
+        (Answer::Text(a), Answer::Text(b)) => a.cmp(b),
+        _ => rank(self).cmp(&rank(other)),
+      },
+    }
+  }
+}
+
+impl Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Answer::Int(n) => write!(f, "{}", n),
+      Answer::UInt(n) => write!(f, "{}", n),
+      Answer::Text(text) => write!(f, "{}", text),
+      Answer::None => write!(f, "none"),
+    }
+  }
+}
+
+impl FromStr for Answer {
+  type Err = std::convert::Infallible;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = s.trim();
+    Ok(if let Ok(n) = s.parse::<i128>() {
+      Answer::Int(n)
+    } else if let Ok(n) = s.parse::<u128>() {
+      Answer::UInt(n)
+    } else if s == "none" {
+      Answer::None
+    } else {
+      Answer::Text(s.to_string())
+    })
+  }
+}
+
+macro_rules! impl_from_signed {
+  ($($t:ty),*) => {$(
+    impl From<$t> for Answer {
+      fn from(n: $t) -> Self {
+        Answer::Int(n as i128)
+      }
+    }
+  )*};
+}
+
+macro_rules! impl_from_unsigned {
+  ($($t:ty),*) => {$(
+    impl From<$t> for Answer {
+      fn from(n: $t) -> Self {
+        match i128::try_from(n) {
+          Ok(n) => Answer::Int(n),
+          Err(_) => Answer::UInt(n as u128),
+        }
+      }
+    }
+  )*};
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+  fn from(text: String) -> Self {
+    Answer::Text(text)
+  }
+}
+
+impl From<&str> for Answer {
+  fn from(text: &str) -> Self {
+    Answer::Text(text.to_string())
+  }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+  fn from(value: Option<T>) -> Self {
+    value.map_or(Answer::None, Into::into)
+  }
+}
+
+// A single integer impl keeps untyped literals unambiguous, so `assert_eq!(answer, 42)` works.
+impl PartialEq<i128> for Answer {
+  fn eq(&self, other: &i128) -> bool {
+    *self == Answer::Int(*other)
+  }
+}
+
+impl PartialEq<&str> for Answer {
+  fn eq(&self, other: &&str) -> bool {
+    matches!(self, Answer::Text(text) if text == other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_integers_compare_by_value() {
+    assert_eq!(Answer::from(42u8), Answer::from(42i64));
+    assert_eq!(Answer::UInt(7), Answer::Int(7));
+    assert_ne!(Answer::Int(-7), Answer::UInt(7));
+    assert_ne!(Answer::from(42), Answer::from("42"));
+    assert!(Answer::from(-3) < Answer::from(2u64));
+    assert!(Answer::UInt(u128::MAX) > Answer::Int(i128::MAX));
+  }
+
+  #[test]
+  fn test_text_form_round_trips() {
+    for answer in [
+      Answer::from(-12),
+      Answer::from(u128::MAX),
+      Answer::from("AB-CD"),
+      Answer::None,
+      Answer::from(None::<u32>),
+    ] {
+      assert!(answer.round_trips());
+      assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+    }
+    for text in ["0042", "-7", "none", "", " AB", "AB\nCD"] {
+      assert!(!Answer::from(text).round_trips(), "{:?}", text);
+    }
+  }
+
+  #[test]
+  fn test_hash_agrees_with_eq() {
+    use std::collections::HashSet;
+
+    let set: HashSet<Answer> = [Answer::Int(5), Answer::UInt(5)].into_iter().collect();
+    assert_eq!(set.len(), 1);
+  }
+}
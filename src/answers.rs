@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Answer, Part};
+
+/// Default location of the known-answers file, relative to the repository root.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
+/// Extension of the sidecar file that sits next to an input, e.g. `d21_simple.expected`.
+pub const SIDECAR_EXTENSION: &str = "expected";
+
+/// Expected answers keyed by day, part and input name.
+///
+/// The file has one `day part input answer` entry per line, where `input` names a file in the
+/// input directory (`full` is `dNN_full.txt`, `simple2` is `dNN_simple2.txt`). Blank lines and
+/// lines starting with `#` are ignored.
+///
+/// Sample inputs keep their answers in a [`Sidecar`] instead, which [`KnownAnswers::load_sidecars`]
+/// merges in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+  entries: BTreeMap<(u8, String, Part), Answer>,
+  params: BTreeMap<(u8, String), Vec<(String, String)>>,
+}
+
+impl KnownAnswers {
+  pub fn load(path: &Path) -> Result<Self, String> {
+    let text =
+      fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e))
+  }
+
+  pub fn parse(text: &str) -> Result<Self, String> {
+    let mut known = KnownAnswers::default();
+
+    for (i, line) in text.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      let mut fields = line.splitn(4, char::is_whitespace);
+      let (Some(day), Some(part), Some(input), Some(answer)) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+      else {
+        return Err(format!("{}: expected `day part input answer`", i + 1));
+      };
+      let day = day
+        .parse()
+        .map_err(|_| format!("{}: invalid day: {}", i + 1, day))?;
+      let part = part.parse().map_err(|e| format!("{}: {}", i + 1, e))?;
+      let answer: Answer = answer.parse().unwrap();
+
+      if known.insert(day, part, input, answer).is_some() {
+        return Err(format!(
+          "{}: duplicate entry for day {} part {} {}",
+          i + 1,
+          day,
+          part,
+          input
+        ));
+      }
+    }
+
+    Ok(known)
+  }
+
+  /// Adds the answers and parameters of every `dNN_<name>.expected` file in `dir`.
+  pub fn load_sidecars(&mut self, dir: &Path) -> Result<(), String> {
+    let entries =
+      fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    let mut paths: Vec<PathBuf> = entries
+      .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+      .filter(|path| path.extension().is_some_and(|ext| ext == SIDECAR_EXTENSION))
+      .collect();
+    paths.sort();
+
+    for path in paths {
+      let Some((day, input)) = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(split_input_name)
+      else {
+        continue;
+      };
+      let sidecar = Sidecar::load(&path)?;
+      for (part, answer) in sidecar.answers {
+        if self.insert(day, part, input, answer).is_some() {
+          return Err(format!(
+            "{}: day {} part {} {} is also in the answers file",
+            path.display(),
+            day,
+            part,
+            input
+          ));
+        }
+      }
+      if !sidecar.params.is_empty() {
+        self.params.insert((day, input.to_string()), sidecar.params);
+      }
+    }
+
+    Ok(())
+  }
+
+  pub fn insert(&mut self, day: u8, part: Part, input: &str, answer: Answer) -> Option<Answer> {
+    self.entries.insert((day, input.to_string(), part), answer)
+  }
+
+  pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&Answer> {
+    self.entries.get(&(day, input.to_string(), part))
+  }
+
+  /// Parameters to set on `day` before solving `input`, in file order.
+  pub fn params(&self, day: u8, input: &str) -> &[(String, String)] {
+    self
+      .params
+      .get(&(day, input.to_string()))
+      .map_or(&[], Vec::as_slice)
+  }
+
+  /// Names of the inputs that have at least one known answer for `day`.
+  pub fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+    let mut names: Vec<&str> = self
+      .entries
+      .keys()
+      .filter(|(d, _, _)| *d == day)
+      .map(|(_, input, _)| input.as_str())
+      .collect();
+    names.dedup();
+    names.into_iter()
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+}
+
+/// Expected answers and puzzle parameters for one input, stored next to it.
+///
+/// Each line is `key = value`. The keys `part1` and `part2` give the expected answers; any other
+/// key is a parameter passed to [`crate::Puzzle::set_param`], such as day 21's `steps`. Blank
+/// lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sidecar {
+  pub answers: BTreeMap<Part, Answer>,
+  pub params: Vec<(String, String)>,
+}
+
+impl Sidecar {
+  pub fn load(path: &Path) -> Result<Self, String> {
+    let text =
+      fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e))
+  }
+
+  pub fn parse(text: &str) -> Result<Self, String> {
+    let mut sidecar = Sidecar::default();
+
+    for (i, line) in text.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      let Some((key, value)) = line.split_once('=') else {
+        return Err(format!("{}: expected `key = value`", i + 1));
+      };
+      let (key, value) = (key.trim(), value.trim());
+      let part = match key {
+        "part1" => Some(Part::One),
+        "part2" => Some(Part::Two),
+        _ => None,
+      };
+
+      let duplicate = match part {
+        Some(part) => sidecar
+          .answers
+          .insert(part, value.parse().unwrap())
+          .is_some(),
+        None if sidecar.params.iter().any(|(name, _)| name == key) => true,
+        None => {
+          sidecar.params.push((key.to_string(), value.to_string()));
+          false
+        }
+      };
+      if duplicate {
+        return Err(format!("{}: duplicate key {}", i + 1, key));
+      }
+    }
+
+    Ok(sidecar)
+  }
+}
+
+/// Path of the sidecar that belongs to the input file at `path`.
+pub fn sidecar_path(path: &Path) -> PathBuf {
+  path.with_extension(SIDECAR_EXTENSION)
+}
+
+/// Splits a file stem such as `d21_simple2` into its day and input name.
+fn split_input_name(stem: &str) -> Option<(u8, &str)> {
+  let (day, name) = stem.strip_prefix('d')?.split_once('_')?;
+  Some((day.parse().ok()?, name))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse() {
+    let known =
+      KnownAnswers::parse("# comment\n\n1 1 full 54081\n22 2 simple 7\n25 2 full none\n").unwrap();
+    assert_eq!(known.len(), 3);
+    assert_eq!(known.get(1, Part::One, "full"), Some(&Answer::from(54081)));
+    assert_eq!(known.get(25, Part::Two, "full"), Some(&Answer::None));
+    assert_eq!(known.get(1, Part::Two, "full"), None);
+    assert_eq!(known.inputs(22).collect::<Vec<_>>(), vec!["simple"]);
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert!(KnownAnswers::parse("1 1 full").is_err());
+    assert!(KnownAnswers::parse("1 3 full 5").is_err());
+    assert!(KnownAnswers::parse("1 1 full 5\n1 1 full 6").is_err());
+  }
+
+  #[test]
+  fn test_parse_sidecar() {
+    let sidecar = Sidecar::parse("# six steps\nsteps = 6\npart1 = 16\n").unwrap();
+    assert_eq!(sidecar.answers.get(&Part::One), Some(&Answer::from(16)));
+    assert_eq!(sidecar.answers.get(&Part::Two), None);
+    assert_eq!(sidecar.params, vec![("steps".to_string(), "6".to_string())]);
+
+    assert!(Sidecar::parse("part1 16").is_err());
+    assert!(Sidecar::parse("part1 = 16\npart1 = 17").is_err());
+    assert!(Sidecar::parse("steps = 6\nsteps = 7").is_err());
+  }
+
+  #[test]
+  fn test_repository_answers_load() {
+    let mut known = KnownAnswers::load(Path::new(DEFAULT_ANSWERS_FILE)).unwrap();
+    known
+      .load_sidecars(Path::new(crate::input::DEFAULT_INPUT_DIR))
+      .unwrap();
+    assert_eq!(known.get(21, Part::One, "simple"), Some(&Answer::from(16)));
+    assert_eq!(
+      known.params(21, "simple"),
+      [("steps".to_string(), "6".to_string())]
+    );
+    assert!(known.params(21, "full").is_empty());
+  }
+}
@@ -0,0 +1,282 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::budget::{self, Budget};
+use crate::{Day, Part};
+
+/// Slowdowns smaller than this are never reported, however large relative to the baseline;
+/// below it the timings are mostly noise.
+pub const MIN_REGRESSION: Duration = Duration::from_micros(100);
+
+/// A separately timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+  Parse,
+  Part(Part),
+}
+
+impl Display for Stage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Stage::Parse => write!(f, "parse"),
+      Stage::Part(part) => write!(f, "{}", part),
+    }
+  }
+}
+
+impl FromStr for Stage {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim() {
+      "parse" => Ok(Stage::Parse),
+      part => part
+        .parse()
+        .map(Stage::Part)
+        .map_err(|_| format!("Invalid stage: {}", s)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+  /// Untimed runs before measuring.
+  pub warmup: usize,
+  /// Timed runs; must be at least 1.
+  pub runs: usize,
+}
+
+impl Default for Options {
+  fn default() -> Self {
+    Options { warmup: 1, runs: 5 }
+  }
+}
+
+/// Summary of the timed runs of one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+  pub median: Duration,
+  pub min: Duration,
+  pub max: Duration,
+}
+
+impl Timing {
+  /// Summarizes `samples`, which must not be empty. An even count takes the lower median.
+  pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+    samples.sort();
+    Timing {
+      median: samples[(samples.len() - 1) / 2],
+      min: samples[0],
+      max: samples[samples.len() - 1],
+    }
+  }
+}
+
+/// Times parsing `input` and then each of `parts` on the parsed puzzle.
+///
+/// `params` are set on the puzzle the parts are timed on, outside the timed region. Each run of
+/// a part gets its own `budget`; the first one to give up ends the bench with its error.
+pub fn bench_day(
+  day: &Day,
+  input: &str,
+  params: &[(String, String)],
+  parts: &[Part],
+  options: Options,
+  budget: Budget,
+) -> Result<Vec<(Stage, Timing)>, String> {
+  let mut results = Vec::new();
+
+  let mut puzzle = day.parse(input).map_err(|e| e.to_string())?;
+  let samples = time_runs(options, || day.parse(input)).map_err(|e| e.to_string())?;
+  results.push((Stage::Parse, Timing::from_samples(samples)));
+
+  for (name, value) in params {
+    puzzle.set_param(name, value)?;
+  }
+  for &part in parts {
+    let samples = time_runs(options, || budget::run(budget, || puzzle.solve(part)))
+      .map_err(|e| format!("part {}: {}", part, e))?;
+    results.push((Stage::Part(part), Timing::from_samples(samples)));
+  }
+
+  Ok(results)
+}
+
+/// Times the runs of `f`, stopping at the first that fails.
+fn time_runs<T, E>(
+  options: Options,
+  mut f: impl FnMut() -> Result<T, E>,
+) -> Result<Vec<Duration>, E> {
+  for _ in 0..options.warmup {
+    black_box(f()?);
+  }
+  (0..options.runs.max(1))
+    .map(|_| {
+      let start = Instant::now();
+      black_box(f()?);
+      Ok(start.elapsed())
+    })
+    .collect()
+}
+
+/// Median timings saved from an earlier run, one `day stage nanoseconds` line each.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+  medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+  pub fn load(path: &Path) -> Result<Self, String> {
+    let text =
+      fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e))
+  }
+
+  pub fn parse(text: &str) -> Result<Self, String> {
+    let mut baseline = Baseline::default();
+
+    for (i, line) in text.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      let fields: Vec<&str> = line.split_whitespace().collect();
+      let [day, stage, nanos] = fields[..] else {
+        return Err(format!("{}: expected `day stage nanoseconds`", i + 1));
+      };
+      let day = day
+        .parse()
+        .map_err(|_| format!("{}: invalid day: {}", i + 1, day))?;
+      let stage = stage.parse().map_err(|e| format!("{}: {}", i + 1, e))?;
+      let nanos = nanos
+        .parse()
+        .map_err(|_| format!("{}: invalid duration: {}", i + 1, nanos))?;
+      baseline.insert(day, stage, Duration::from_nanos(nanos));
+    }
+
+    Ok(baseline)
+  }
+
+  pub fn save(&self, path: &Path) -> Result<(), String> {
+    fs::write(path, self.to_string())
+      .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+  }
+
+  pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+    self.medians.insert((day, stage), median);
+  }
+
+  pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+    self.medians.get(&(day, stage)).copied()
+  }
+}
+
+impl Display for Baseline {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "# aoc bench baseline: day stage median-nanoseconds")?;
+    for ((day, stage), median) in &self.medians {
+      writeln!(f, "{} {} {}", day, stage, median.as_nanos())?;
+    }
+    Ok(())
+  }
+}
+
+/// Whether `current` is more than `threshold` (a fraction, e.g. `0.1` for 10%) slower than
+/// `baseline`, and by at least [`MIN_REGRESSION`].
+pub fn is_regression(current: Duration, baseline: Duration, threshold: f64) -> bool {
+  current > baseline + MIN_REGRESSION
+    && current.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::days;
+
+  #[test]
+  fn test_timing_from_samples() {
+    let ms = Duration::from_millis;
+    let timing = Timing::from_samples(vec![ms(5), ms(1), ms(9), ms(3)]);
+    assert_eq!(
+      timing,
+      Timing {
+        median: ms(3),
+        min: ms(1),
+        max: ms(9),
+      }
+    );
+  }
+
+  #[test]
+  fn test_baseline_round_trips() {
+    let mut baseline = Baseline::default();
+    baseline.insert(23, Stage::Part(Part::Two), Duration::from_millis(1500));
+    baseline.insert(1, Stage::Parse, Duration::from_nanos(42));
+
+    let parsed = Baseline::parse(&baseline.to_string()).unwrap();
+    assert_eq!(parsed, baseline);
+    assert_eq!(parsed.get(1, Stage::Parse), Some(Duration::from_nanos(42)));
+    assert!(Baseline::parse("1 3 100").is_err());
+  }
+
+  #[test]
+  fn test_is_regression() {
+    let ms = Duration::from_millis;
+    assert!(is_regression(ms(12), ms(10), 0.1));
+    assert!(!is_regression(ms(11), ms(10), 0.2));
+    assert!(!is_regression(ms(8), ms(10), 0.1));
+    assert!(!is_regression(
+      Duration::from_micros(30),
+      Duration::from_micros(10),
+      0.1
+    ));
+  }
+
+  #[test]
+  fn test_bench_day_times_each_stage() {
+    let options = Options { warmup: 0, runs: 3 };
+    let results = bench_day(
+      days::get(6).unwrap(),
+      "Time: 7 15 30\nDistance: 9 40 200\n",
+      &[],
+      &Part::BOTH,
+      options,
+      Budget::UNLIMITED,
+    )
+    .unwrap();
+    let stages: Vec<Stage> = results.iter().map(|(stage, _)| *stage).collect();
+    assert_eq!(
+      stages,
+      vec![Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)]
+    );
+    assert!(
+      results
+        .iter()
+        .all(|(_, t)| t.min <= t.median && t.median <= t.max)
+    );
+  }
+
+  #[test]
+  fn test_bench_day_stops_when_a_part_gives_up() {
+    // Every cut of five components wired to each other has at least four wires.
+    let budget = Budget {
+      time: None,
+      steps: Some(100),
+    };
+    let options = Options { warmup: 0, runs: 3 };
+    let day = days::get(25).unwrap();
+    let input = "a: b c d e\nb: c d e\nc: d e\nd: e\n";
+    let e = bench_day(day, input, &[], &[Part::One], options, budget).unwrap_err();
+    assert!(
+      e.starts_with("part 1: gave up after 100 contractions"),
+      "{}",
+      e
+    );
+  }
+}
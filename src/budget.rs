@@ -0,0 +1,216 @@
+//! Cooperative limits on how long a solver may search.
+//!
+//! Some solvers have no bound on their running time for arbitrary inputs: random searches that
+//! may never succeed, walks that may never arrive. They call [`tick`] once per unit of work, such
+//! as a sample or a step. Inside [`run`], the tick that goes past the budget stops the solver and
+//! `run` returns [`GaveUp`] with the work done so far. Outside of `run` a tick only counts down a
+//! thread-local, so solvers called directly, as in the tests, are never stopped.
+
+use std::cell::Cell;
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Ticks between two readings of the clock, which costs more than counting.
+const CLOCK_INTERVAL: u64 = 256;
+
+/// How much work a solver may do before it gives up. `None` means no limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Budget {
+  pub time: Option<Duration>,
+  /// Calls to [`tick`], whatever unit of work each stands for.
+  pub steps: Option<u64>,
+}
+
+impl Budget {
+  pub const UNLIMITED: Budget = Budget {
+    time: None,
+    steps: None,
+  };
+
+  pub fn time(time: Duration) -> Self {
+    Budget {
+      time: Some(time),
+      steps: None,
+    }
+  }
+}
+
+/// The limit a solver ran into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+  Time(Duration),
+  Steps(u64),
+}
+
+impl Display for Limit {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Limit::Time(time) => write!(f, "time limit of {:.2?}", time),
+      Limit::Steps(steps) => write!(f, "limit of {} steps", steps),
+    }
+  }
+}
+
+/// A solver stopped by its [`Budget`], with how far it got.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaveUp {
+  pub limit: Limit,
+  /// Ticks counted before giving up.
+  pub steps: u64,
+  /// What the last tick stood for in the solver, e.g. `"samples"`.
+  pub unit: &'static str,
+  pub elapsed: Duration,
+}
+
+impl Display for GaveUp {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "gave up after {} {} in {:.2?}, past the {}",
+      self.steps, self.unit, self.elapsed, self.limit
+    )
+  }
+}
+
+impl std::error::Error for GaveUp {}
+
+#[derive(Clone, Copy)]
+struct State {
+  budget: Budget,
+  start: Instant,
+  /// Ticks counted up to the next check, which is due when `COUNTDOWN` runs out.
+  steps: u64,
+}
+
+thread_local! {
+  static STATE: Cell<Option<State>> = const { Cell::new(None) };
+  /// Ticks left before [`tick`] looks at `STATE`; the common case only counts this down.
+  static COUNTDOWN: Cell<u64> = const { Cell::new(u64::MAX) };
+}
+
+/// Runs `f` with `budget` for the [`tick`]s it makes on this thread.
+///
+/// Panics in `f` other than giving up are passed on. Budgets do not nest: an inner `run` replaces
+/// the outer budget until it returns.
+pub fn run<T>(budget: Budget, f: impl FnOnce() -> T) -> Result<T, GaveUp> {
+  let outer = (STATE.get(), COUNTDOWN.get());
+  schedule(State {
+    budget,
+    start: Instant::now(),
+    steps: 0,
+  });
+  let result = panic::catch_unwind(AssertUnwindSafe(f));
+  STATE.set(outer.0);
+  COUNTDOWN.set(outer.1);
+
+  result.or_else(|payload| match payload.downcast::<GaveUp>() {
+    Ok(gave_up) => Err(*gave_up),
+    Err(payload) => panic::resume_unwind(payload),
+  })
+}
+
+/// Counts one unit of work, described by `unit`, against the budget of the enclosing [`run`],
+/// and stops the solver if the budget is spent.
+///
+/// The solver unwinds without running the panic hook, so nothing is printed.
+pub fn tick(unit: &'static str) {
+  let left = COUNTDOWN.get();
+  if left > 1 {
+    COUNTDOWN.set(left - 1);
+  } else {
+    check(unit);
+  }
+}
+
+fn check(unit: &'static str) {
+  let Some(state) = STATE.get() else {
+    COUNTDOWN.set(u64::MAX);
+    return;
+  };
+
+  let limit = match state.budget {
+    Budget {
+      steps: Some(steps), ..
+    } if state.steps > steps => Limit::Steps(steps),
+    Budget {
+      time: Some(time), ..
+    } if state.start.elapsed() > time => Limit::Time(time),
+    _ => return schedule(state),
+  };
+  panic::resume_unwind(Box::new(GaveUp {
+    limit,
+    steps: state.steps - 1,
+    unit,
+    elapsed: state.start.elapsed(),
+  }));
+}
+
+/// Sets the countdown to the next check: after [`CLOCK_INTERVAL`] ticks, or sooner at the tick
+/// that would go past the step limit.
+fn schedule(mut state: State) {
+  let mut interval = CLOCK_INTERVAL;
+  if let Some(steps) = state.budget.steps {
+    interval = interval.min((steps + 1).saturating_sub(state.steps).max(1));
+  }
+  state.steps += interval;
+  STATE.set(Some(state));
+  COUNTDOWN.set(interval);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn count(n: u64) -> u64 {
+    for _ in 0..n {
+      tick("things");
+    }
+    n
+  }
+
+  #[test]
+  fn test_step_limit() {
+    let budget = Budget {
+      time: None,
+      steps: Some(10),
+    };
+    assert_eq!(run(budget, || count(10)), Ok(10));
+
+    let gave_up = run(budget, || count(11)).unwrap_err();
+    assert_eq!((gave_up.limit, gave_up.steps), (Limit::Steps(10), 10));
+    assert!(
+      gave_up
+        .to_string()
+        .starts_with("gave up after 10 things in ")
+    );
+  }
+
+  #[test]
+  fn test_time_limit() {
+    let budget = Budget::time(Duration::from_millis(10));
+    let gave_up = run(budget, || {
+      loop {
+        tick("spins");
+      }
+    })
+    .unwrap_err();
+    assert_eq!(gave_up.limit, Limit::Time(Duration::from_millis(10)));
+    assert!(gave_up.elapsed >= Duration::from_millis(10));
+  }
+
+  #[test]
+  fn test_ticks_outside_run_are_free() {
+    assert_eq!(count(1000), 1000);
+    assert_eq!(run(Budget::UNLIMITED, || count(1000)), Ok(1000));
+    // The budget ends with `run`.
+    let _ = run(Budget::time(Duration::ZERO), || count(CLOCK_INTERVAL));
+    assert_eq!(count(CLOCK_INTERVAL), CLOCK_INTERVAL);
+  }
+
+  #[test]
+  fn test_other_panics_pass_through() {
+    let result = panic::catch_unwind(|| run(Budget::UNLIMITED, || panic!("boom")));
+    assert!(result.is_err());
+  }
+}
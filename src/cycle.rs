@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating: the state at step `start + period` is the first
+/// to equal an earlier one, the state at step `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+  /// Steps before the cycle is entered, its transient length.
+  pub start: usize,
+  pub period: usize,
+}
+
+impl Cycle {
+  /// The earliest step whose state equals the state at step `n`.
+  pub fn earliest_equivalent(self, n: usize) -> usize {
+    if n < self.start {
+      n
+    } else {
+      self.start + (n - self.start) % self.period
+    }
+  }
+}
+
+/// Finds the cycle of the states `initial`, `step(initial)`, ... by remembering every state.
+/// Also returns the states at steps `0..start + period`.
+///
+/// The sequence must eventually repeat, as any sequence over finitely many states does.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> (Cycle, Vec<S>)
+where
+  S: Clone + Eq + Hash,
+  F: FnMut(&S) -> S,
+{
+  let mut seen = HashMap::new();
+  let mut states = Vec::new();
+  let mut state = initial;
+
+  loop {
+    match seen.entry(state.clone()) {
+      Entry::Occupied(entry) => {
+        let start = *entry.get();
+        let cycle = Cycle {
+          start,
+          period: states.len() - start,
+        };
+        return (cycle, states);
+      }
+      Entry::Vacant(entry) => {
+        entry.insert(states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+      }
+    }
+  }
+}
+
+/// The state after `n` steps from `initial`, simulating only until the states start repeating.
+pub fn state_at<S, F>(initial: S, n: usize, mut step: F) -> S
+where
+  S: Clone + Eq + Hash,
+  F: FnMut(&S) -> S,
+{
+  let mut seen = HashMap::new();
+  let mut states = Vec::new();
+  let mut state = initial;
+
+  for i in 0..n {
+    if let Some(&start) = seen.get(&state) {
+      let cycle = Cycle {
+        start,
+        period: i - start,
+      };
+      return states.swap_remove(cycle.earliest_equivalent(n));
+    }
+    seen.insert(state.clone(), i);
+    let next = step(&state);
+    states.push(state);
+    state = next;
+  }
+
+  state
+}
+
+/// [`find_cycle`] in constant memory with Floyd's tortoise and hare, at the cost of running
+/// `step` about three times as often. `step` must give the same result for the same state.
+pub fn floyd<S, F>(initial: S, mut step: F) -> Cycle
+where
+  S: Clone + Eq,
+  F: FnMut(&S) -> S,
+{
+  let mut tortoise = step(&initial);
+  let mut hare = step(&tortoise);
+  while tortoise != hare {
+    tortoise = step(&tortoise);
+    let halfway = step(&hare);
+    hare = step(&halfway);
+  }
+
+  let mut start = 0;
+  tortoise = initial;
+  while tortoise != hare {
+    tortoise = step(&tortoise);
+    hare = step(&hare);
+    start += 1;
+  }
+
+  let mut period = 1;
+  hare = step(&tortoise);
+  while tortoise != hare {
+    hare = step(&hare);
+    period += 1;
+  }
+
+  Cycle { start, period }
+}
+
+/// [`find_cycle`] in constant memory with Brent's algorithm, which usually runs `step` fewer
+/// times than [`floyd`]. `step` must give the same result for the same state.
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+  S: Clone + Eq,
+  F: FnMut(&S) -> S,
+{
+  let mut power = 1;
+  let mut period = 1;
+  let mut tortoise = initial.clone();
+  let mut hare = step(&initial);
+  while tortoise != hare {
+    if power == period {
+      tortoise = hare.clone();
+      power *= 2;
+      period = 0;
+    }
+    hare = step(&hare);
+    period += 1;
+  }
+
+  tortoise = initial.clone();
+  hare = initial;
+  for _ in 0..period {
+    hare = step(&hare);
+  }
+  let mut start = 0;
+  while tortoise != hare {
+    tortoise = step(&tortoise);
+    hare = step(&hare);
+    start += 1;
+  }
+
+  Cycle { start, period }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// 0 → 1 → 2 → 3 → 4 → 5 → 6 → 3, a transient of 3 and a period of 4.
+  fn next(&x: &u32) -> u32 {
+    if x == 6 { 3 } else { x + 1 }
+  }
+
+  const EXPECTED: Cycle = Cycle {
+    start: 3,
+    period: 4,
+  };
+
+  #[test]
+  fn test_find_cycle() {
+    let (cycle, states) = find_cycle(0, next);
+    assert_eq!(cycle, EXPECTED);
+    assert_eq!(states, vec![0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(
+      find_cycle(7, |&x| x).0,
+      Cycle {
+        start: 0,
+        period: 1,
+      }
+    );
+  }
+
+  #[test]
+  fn test_low_memory_detection_agrees() {
+    assert_eq!(floyd(0, next), EXPECTED);
+    assert_eq!(brent(0, next), EXPECTED);
+
+    let square_plus_one = |&x: &u64| (x * x + 1) % 1009;
+    let (cycle, _) = find_cycle(2, square_plus_one);
+    assert_eq!(floyd(2, square_plus_one), cycle);
+    assert_eq!(brent(2, square_plus_one), cycle);
+  }
+
+  #[test]
+  fn test_state_at_extrapolates() {
+    assert_eq!(EXPECTED.earliest_equivalent(2), 2);
+    assert_eq!(EXPECTED.earliest_equivalent(12), 4);
+    for n in 0..40 {
+      let direct = (0..n).fold(0, |x, _| next(&x));
+      assert_eq!(state_at(0, n, next), direct, "step {}", n);
+    }
+    assert_eq!(state_at(0, 1_000_000_000, next), 4);
+  }
+}
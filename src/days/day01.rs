@@ -0,0 +1,106 @@
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day01 {
+  lines: Vec<String>,
+}
+
+impl Solution for Day01 {
+  const DAY: u8 = 1;
+  const TITLE: &'static str = "Trebuchet?!";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day01 {
+      lines: input.lines().map(String::from).collect(),
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    part_one(&self.lines).into()
+  }
+
+  fn part2(&self) -> Answer {
+    part_two(&self.lines).into()
+  }
+}
+
+/// The sum of the calibration values, or `None` if a line has no digit to take one from.
+fn part_one(lines: &[String]) -> Option<u32> {
+  lines
+    .iter()
+    .map(|line| extract_calibration_value(line))
+    .sum()
+}
+
+fn part_two(lines: &[String]) -> Option<u32> {
+  lines
+    .iter()
+    .map(|line| extract_calibration_value_with_words(line))
+    .sum()
+}
+
+fn extract_calibration_value(line: &str) -> Option<u32> {
+  let digits: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
+  Some(digits.first()? * 10 + digits.last()?)
+}
+
+fn extract_calibration_value_with_words(line: &str) -> Option<u32> {
+  let words = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+  ];
+  let mut digits = Vec::new();
+
+  for (i, c) in line.char_indices() {
+    if c.is_ascii_digit() {
+      digits.push(c.to_digit(10).unwrap());
+    } else {
+      for (j, word) in words.iter().enumerate() {
+        if line[i..].starts_with(word) {
+          digits.push(j as u32 + 1);
+          break;
+        }
+      }
+    }
+  }
+
+  Some(digits.first()? * 10 + digits.last()?)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day01>("input/d01_simple1.txt", Part::One), 142);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day01>("input/d01_full.txt", Part::One), 54081);
+  }
+
+  #[test]
+  fn test_overlapping_words() {
+    assert_eq!(extract_calibration_value_with_words("oneight"), Some(18));
+    assert_eq!(
+      extract_calibration_value_with_words("threeightwo"),
+      Some(32)
+    );
+    assert_eq!(
+      extract_calibration_value_with_words("fiveighthree"),
+      Some(53)
+    );
+    assert_eq!(extract_calibration_value_with_words("xyz"), None);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day01>("input/d01_simple2.txt", Part::Two), 281);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day01>("input/d01_full.txt", Part::Two), 54649);
+  }
+}
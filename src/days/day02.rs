@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day02 {
+  games: Vec<Game>,
+}
+
+impl Solution for Day02 {
+  const DAY: u8 = 2;
+  const TITLE: &'static str = "Cube Conundrum";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day02 {
+      games: parse_input(input)?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    let max_cubes = HashMap::from([
+      ("red".to_string(), 12),
+      ("green".to_string(), 13),
+      ("blue".to_string(), 14),
+    ]);
+
+    self
+      .games
+      .iter()
+      .filter(|game| is_game_possible(game, &max_cubes))
+      .map(|game| game.id)
+      .sum::<u32>()
+      .into()
+  }
+
+  fn part2(&self) -> Answer {
+    self
+      .games
+      .iter()
+      .map(minimum_cubes_power)
+      .sum::<u32>()
+      .into()
+  }
+}
+
+#[derive(Debug)]
+struct Game {
+  id: u32,
+  sets: Vec<HashMap<String, u32>>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+  parse::lines(input).map(parse_game).collect()
+}
+
+fn parse_game(line: Line) -> Result<Game, ParseError> {
+  let (header, sets) = line
+    .text
+    .split_once(": ")
+    .ok_or_else(|| line.expected("Game <id>: <sets>"))?;
+  let id = header
+    .strip_prefix("Game ")
+    .ok_or_else(|| line.error(header, "expected `Game <id>`, got"))?;
+  let id = line.parse(id, "game id")?;
+
+  let sets = sets
+    .split("; ")
+    .map(|set| {
+      set
+        .split(", ")
+        .map(|cube| {
+          let (count, color) = cube
+            .split_once(' ')
+            .ok_or_else(|| line.error(cube, "expected `<count> <color>`, got"))?;
+          Ok((color.to_string(), line.parse(count, "cube count")?))
+        })
+        .collect()
+    })
+    .collect::<Result<_, _>>()?;
+
+  Ok(Game { id, sets })
+}
+
+fn is_game_possible(game: &Game, max_cubes: &HashMap<String, u32>) -> bool {
+  game.sets.iter().all(|set| {
+    set
+      .iter()
+      .all(|(color, &count)| count <= *max_cubes.get(color).unwrap_or(&0))
+  })
+}
+
+fn minimum_cubes_power(game: &Game) -> u32 {
+  let mut min_cubes = HashMap::new();
+  for set in &game.sets {
+    for (color, &count) in set {
+      min_cubes
+        .entry(color.clone())
+        .and_modify(|e: &mut u32| *e = (*e).max(count))
+        .or_insert(count);
+    }
+  }
+  min_cubes.values().product()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day02>("input/d02_simple.txt", Part::One), 8);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day02>("input/d02_full.txt", Part::One), 2207);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day02>("input/d02_simple.txt", Part::Two), 2286);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day02>("input/d02_full.txt", Part::Two), 62241);
+  }
+}
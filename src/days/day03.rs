@@ -0,0 +1,149 @@
+use crate::grid::{Coord, Grid};
+use crate::{Answer, ParseError, Solution, parse};
+
+pub struct Day03 {
+  schematic: Schematic,
+}
+
+impl Solution for Day03 {
+  const DAY: u8 = 3;
+  const TITLE: &'static str = "Gear Ratios";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day03 {
+      schematic: Schematic::new(input)?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    self.schematic.sum_part_numbers().into()
+  }
+
+  fn part2(&self) -> Answer {
+    self.schematic.sum_gear_ratios().into()
+  }
+}
+
+struct Schematic {
+  grid: Grid<char>,
+  /// The value of every number in the schematic.
+  numbers: Vec<u32>,
+  /// For each cell, the index in `numbers` of the number it is a digit of.
+  number_at: Grid<Option<usize>>,
+}
+
+impl Schematic {
+  fn new(input: &str) -> Result<Self, ParseError> {
+    let grid = parse::grid(parse::lines(input), "character", Some)?;
+    let mut numbers: Vec<u32> = Vec::new();
+    let mut number_at = Grid::new(grid.width(), grid.height(), None);
+
+    for y in 0..grid.height() {
+      let mut current = None;
+      for (x, &c) in grid.row(y).iter().enumerate() {
+        let Some(digit) = c.to_digit(10) else {
+          current = None;
+          continue;
+        };
+        let index = *current.get_or_insert_with(|| {
+          numbers.push(0);
+          numbers.len() - 1
+        });
+        numbers[index] = numbers[index]
+          .checked_mul(10)
+          .and_then(|number| number.checked_add(digit))
+          .ok_or_else(|| match parse::lines(input).nth(y) {
+            Some(line) => line.error(line.text, "number too large in"),
+            None => ParseError::new("number too large"),
+          })?;
+        number_at[Coord::new(x, y)] = Some(index);
+      }
+    }
+
+    Ok(Self {
+      grid,
+      numbers,
+      number_at,
+    })
+  }
+
+  /// Indices of the distinct numbers with a digit next to `coord`, diagonals included.
+  fn adjacent_numbers(&self, coord: Coord) -> Vec<usize> {
+    let mut adjacent: Vec<usize> = self
+      .grid
+      .neighbors8(coord)
+      .filter_map(|neighbor| self.number_at[neighbor])
+      .collect();
+    adjacent.sort_unstable();
+    adjacent.dedup();
+    adjacent
+  }
+
+  fn sum_part_numbers(&self) -> u64 {
+    let mut is_part = vec![false; self.numbers.len()];
+    for (coord, &c) in self.grid.iter() {
+      if !c.is_ascii_digit() && c != '.' {
+        for index in self.adjacent_numbers(coord) {
+          is_part[index] = true;
+        }
+      }
+    }
+
+    self
+      .numbers
+      .iter()
+      .zip(is_part)
+      .filter(|&(_, is_part)| is_part)
+      .map(|(&number, _)| u64::from(number))
+      .sum()
+  }
+
+  fn sum_gear_ratios(&self) -> u128 {
+    self
+      .grid
+      .find_all(&'*')
+      .filter_map(|coord| match self.adjacent_numbers(coord)[..] {
+        [a, b] => Some(u128::from(self.numbers[a]) * u128::from(self.numbers[b])),
+        _ => None,
+      })
+      .sum()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day03>("input/d03_simple.txt", Part::One), 4361);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day03>("input/d03_full.txt", Part::One), 521601);
+  }
+
+  #[test]
+  fn test_rightmost_edge_cases() {
+    let schematic = Schematic::new("123*\n...*\n456*").unwrap();
+    assert_eq!(schematic.sum_gear_ratios(), 56088);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(
+      solve_file::<Day03>("input/d03_simple.txt", Part::Two),
+      467835
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day03>("input/d03_full.txt", Part::Two),
+      80694070
+    );
+  }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+/// Number of winning numbers on each card, in card order.
+pub struct Day04 {
+  matches: Vec<usize>,
+}
+
+impl Solution for Day04 {
+  const DAY: u8 = 4;
+  const TITLE: &'static str = "Scratchcards";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day04 {
+      matches: parse::lines(input)
+        .map(count_matches)
+        .collect::<Result<_, _>>()?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    solve_part1(&self.matches).into()
+  }
+
+  fn part2(&self) -> Answer {
+    solve_part2(&self.matches).into()
+  }
+}
+
+fn solve_part1(matches: &[usize]) -> u32 {
+  matches.iter().map(|&m| calculate_card_points(m)).sum()
+}
+
+fn solve_part2(matches: &[usize]) -> u32 {
+  let mut card_counts: HashMap<usize, u32> = HashMap::new();
+
+  for (i, &matches_on_card) in matches.iter().enumerate() {
+    let card_number = i + 1;
+    let current_count = *card_counts.entry(card_number).or_insert(1);
+
+    for j in 1..=matches_on_card {
+      let next_card = card_number + j;
+      if next_card <= matches.len() {
+        *card_counts.entry(next_card).or_insert(1) += current_count;
+      }
+    }
+  }
+
+  card_counts.values().sum()
+}
+
+fn calculate_card_points(matches: usize) -> u32 {
+  if matches == 0 {
+    0
+  } else {
+    2u32.pow(matches as u32 - 1)
+  }
+}
+
+fn count_matches(line: Line) -> Result<usize, ParseError> {
+  let (winning, mine) = line
+    .text
+    .split_once(": ")
+    .and_then(|(_, numbers)| numbers.split_once(" | "))
+    .ok_or_else(|| line.expected("Card <id>: <winning> | <numbers>"))?;
+  let numbers = |list: &str| -> Result<Vec<u32>, ParseError> {
+    list
+      .split_whitespace()
+      .map(|n| line.parse(n, "number"))
+      .collect()
+  };
+  let winning_numbers = numbers(winning)?;
+  let my_numbers = numbers(mine)?;
+
+  Ok(
+    my_numbers
+      .iter()
+      .filter(|&n| winning_numbers.contains(n))
+      .count(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day04>("input/d04_simple.txt", Part::One), 13);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day04>("input/d04_full.txt", Part::One), 17782);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day04>("input/d04_simple.txt", Part::Two), 30);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day04>("input/d04_full.txt", Part::Two),
+      8477787
+    );
+  }
+}
@@ -0,0 +1,219 @@
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day05 {
+  seeds_part1: Vec<Range>,
+  seeds_part2: Vec<Range>,
+  maps: Vec<Map>,
+}
+
+impl Solution for Day05 {
+  const DAY: u8 = 5;
+  const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let (seeds_part1, seeds_part2, maps) = parse_input(input)?;
+    Ok(Day05 {
+      seeds_part1,
+      seeds_part2,
+      maps,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    lowest_location(&self.seeds_part1, &self.maps).into()
+  }
+
+  fn part2(&self) -> Answer {
+    lowest_location(&self.seeds_part2, &self.maps).into()
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Range {
+  start: u64,
+  end: u64,
+}
+
+impl Range {
+  /// The range of `length` numbers from `start`, or `None` if it would pass `u64::MAX`.
+  fn new(start: u64, length: u64) -> Option<Self> {
+    Some(Range {
+      start,
+      end: start.checked_add(length)?,
+    })
+  }
+
+  fn overlap(&self, other: &Range) -> Option<Range> {
+    let start = self.start.max(other.start);
+    let end = self.end.min(other.end);
+    if start < end {
+      Some(Range { start, end })
+    } else {
+      None
+    }
+  }
+}
+
+/// Source ranges, each with the start of the destination range it maps to.
+#[derive(Debug)]
+struct Map {
+  ranges: Vec<(Range, u64)>,
+}
+
+impl Map {
+  fn apply(&self, input: Range) -> Vec<Range> {
+    let mut result = vec![];
+    let mut to_process = vec![input];
+
+    while let Some(current) = to_process.pop() {
+      let mut mapped = false;
+      for &(range, destination) in &self.ranges {
+        if let Some(overlap) = current.overlap(&range) {
+          result.push(Range {
+            start: destination + (overlap.start - range.start),
+            end: destination + (overlap.end - range.start),
+          });
+          if overlap.start > current.start {
+            to_process.push(Range {
+              start: current.start,
+              end: overlap.start,
+            });
+          }
+          if current.end > overlap.end {
+            to_process.push(Range {
+              start: overlap.end,
+              end: current.end,
+            });
+          }
+          mapped = true;
+          break;
+        }
+      }
+      if !mapped {
+        result.push(current);
+      }
+    }
+    result
+  }
+}
+
+type Parsed = (Vec<Range>, Vec<Range>, Vec<Map>);
+
+fn parse_input(input: &str) -> Result<Parsed, ParseError> {
+  let sections = parse::sections(input);
+  let Some((seeds, maps)) = sections.split_first() else {
+    return Err(ParseError::new("empty input"));
+  };
+  if seeds.len() != 1 {
+    return Err(seeds[1].expected("seeds: <numbers> followed by a blank line"));
+  }
+
+  let line = seeds[0];
+  let numbers = line
+    .text
+    .strip_prefix("seeds:")
+    .ok_or_else(|| line.expected("seeds: <numbers>"))?;
+  let seed_numbers: Vec<u64> = numbers
+    .split_whitespace()
+    .map(|s| line.parse(s, "seed"))
+    .collect::<Result<_, _>>()?;
+  if seed_numbers.is_empty() {
+    return Err(line.error(numbers, "expected seed numbers, got"));
+  }
+  if !seed_numbers.len().is_multiple_of(2) {
+    return Err(line.error(numbers, "expected pairs of seed numbers, got"));
+  }
+
+  let seeds_part1: Vec<Range> = seed_numbers
+    .iter()
+    .map(|&seed| Range::new(seed, 1))
+    .collect::<Option<_>>()
+    .ok_or_else(|| line.error(numbers, "seed out of range in"))?;
+
+  let seeds_part2: Vec<Range> = seed_numbers
+    .chunks(2)
+    .map(|chunk| Range::new(chunk[0], chunk[1]))
+    .collect::<Option<_>>()
+    .ok_or_else(|| line.error(numbers, "seed range out of range in"))?;
+
+  let maps = maps
+    .iter()
+    .map(|section| {
+      let ranges = section[1..]
+        .iter()
+        .map(|&line| parse_range(line))
+        .collect::<Result<_, _>>()?;
+      Ok(Map { ranges })
+    })
+    .collect::<Result<_, _>>()?;
+
+  Ok((seeds_part1, seeds_part2, maps))
+}
+
+fn parse_range(line: Line) -> Result<(Range, u64), ParseError> {
+  let fields: Vec<&str> = line.text.split_whitespace().collect();
+  let [dest_start, src_start, length] = fields[..] else {
+    return Err(line.expected("<destination> <source> <length>"));
+  };
+  let dest_start: u64 = line.parse(dest_start, "destination start")?;
+  let src_start: u64 = line.parse(src_start, "source start")?;
+  let length = line.parse(length, "range length")?;
+
+  match (
+    Range::new(src_start, length),
+    Range::new(dest_start, length),
+  ) {
+    (Some(source), Some(_)) => Ok((source, dest_start)),
+    _ => Err(line.error(line.text, "range passes the largest number in")),
+  }
+}
+
+/// The lowest location of any seed, or `None` if the seed ranges are all empty.
+fn lowest_location(seeds: &[Range], maps: &[Map]) -> Option<u64> {
+  seeds
+    .iter()
+    .filter(|seed| seed.start < seed.end)
+    .flat_map(|&seed| {
+      maps.iter().fold(vec![seed], |ranges, map| {
+        ranges
+          .into_iter()
+          .flat_map(|range| map.apply(range))
+          .collect()
+      })
+    })
+    .map(|range| range.start)
+    .min()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day05>("input/d05_simple.txt", Part::One), 35);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(
+      solve_file::<Day05>("input/d05_full.txt", Part::One),
+      1181555926
+    );
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day05>("input/d05_simple.txt", Part::Two), 46);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day05>("input/d05_full.txt", Part::Two),
+      37806486
+    );
+  }
+}
@@ -0,0 +1,148 @@
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day06 {
+  times: Vec<u64>,
+  distances: Vec<u64>,
+  time: u64,
+  distance: u64,
+}
+
+impl Solution for Day06 {
+  const DAY: u8 = 6;
+  const TITLE: &'static str = "Wait For It";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let lines: Vec<Line> = parse::lines(input).collect();
+    let [time_line, distance_line] = lines[..] else {
+      return Err(ParseError::new(format!(
+        "expected a Time and a Distance line, got {} lines",
+        lines.len()
+      )));
+    };
+    let time_values = field(time_line, "Time:")?;
+    let distance_values = field(distance_line, "Distance:")?;
+
+    let (times, distances) = parse_input(time_line, time_values, distance_line, distance_values)?;
+    let time = parse_joined(time_line, time_values, "time")?;
+    let distance = parse_joined(distance_line, distance_values, "distance")?;
+    Ok(Day06 {
+      times,
+      distances,
+      time,
+      distance,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    solve_part1(&self.times, &self.distances).into()
+  }
+
+  fn part2(&self) -> Answer {
+    count_ways_to_win(self.time, self.distance).into()
+  }
+}
+
+/// The text after `label` on `line`.
+fn field<'a>(line: Line<'a>, label: &str) -> Result<&'a str, ParseError> {
+  line
+    .text
+    .strip_prefix(label)
+    .ok_or_else(|| line.expected(&format!("{} <numbers>", label)))
+}
+
+fn parse_input(
+  time_line: Line,
+  times: &str,
+  distance_line: Line,
+  distances: &str,
+) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+  let times: Vec<u64> = times
+    .split_whitespace()
+    .map(|s| time_line.parse(s, "time"))
+    .collect::<Result<_, _>>()?;
+  let distances: Vec<u64> = distances
+    .split_whitespace()
+    .map(|s| distance_line.parse(s, "distance"))
+    .collect::<Result<_, _>>()?;
+  if times.len() != distances.len() {
+    return Err(distance_line.error(
+      distance_line.text,
+      format!("expected {} distances to match the times, got", times.len()),
+    ));
+  }
+  Ok((times, distances))
+}
+
+/// The number of whole milliseconds to hold the button that beat `distance` in a race of `time`.
+///
+/// The distance `hold * (time - hold)` grows until half the time and then shrinks in mirror
+/// image, so the winning holds are the range between the shortest one and its mirror, and the
+/// shortest is found by binary search.
+fn count_ways_to_win(time: u64, distance: u64) -> u64 {
+  let beats = |hold: u64| u128::from(hold) * u128::from(time - hold) > u128::from(distance);
+  let half = time / 2;
+  if !beats(half) {
+    return 0;
+  }
+  let (mut losing, mut winning) = (0, half);
+  while winning - losing > 1 {
+    let middle = losing + (winning - losing) / 2;
+    if beats(middle) {
+      winning = middle;
+    } else {
+      losing = middle;
+    }
+  }
+  time - 2 * winning + 1
+}
+
+/// The product of the ways to win each race, or `None` if it does not fit in a `u128`.
+fn solve_part1(times: &[u64], distances: &[u64]) -> Option<u128> {
+  times
+    .iter()
+    .zip(distances.iter())
+    .try_fold(1u128, |product, (&t, &d)| {
+      product.checked_mul(u128::from(count_ways_to_win(t, d)))
+    })
+}
+
+/// Part 2 reads each line as a single number, ignoring the spaces between its digits.
+fn parse_joined(line: Line, values: &str, what: &str) -> Result<u64, ParseError> {
+  values
+    .replace(" ", "")
+    .parse()
+    .map_err(|_| line.error(values, format!("invalid {}", what)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day06>("input/d06_simple.txt", Part::One), 288);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day06>("input/d06_full.txt", Part::One), 771628);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(
+      solve_file::<Day06>("input/d06_simple.txt", Part::Two),
+      71503
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day06>("input/d06_full.txt", Part::Two),
+      27363861
+    );
+  }
+}
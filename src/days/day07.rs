@@ -1,8 +1,33 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day07 {
+  hands: Vec<Hand>,
+}
+
+impl Solution for Day07 {
+  const DAY: u8 = 7;
+  const TITLE: &'static str = "Camel Cards";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day07 {
+      hands: parse::lines(input)
+        .map(parse_hand)
+        .collect::<Result<_, _>>()?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    solve_part1(&self.hands).into()
+  }
+
+  fn part2(&self) -> Answer {
+    solve_part2(&self.hands).into()
+  }
+}
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 enum HandType {
@@ -22,26 +47,25 @@ struct Hand {
   hand_type: HandType,
 }
 
-fn parse_input<P: AsRef<Path>>(path: P) -> io::Result<Vec<Hand>> {
-  let file = File::open(path)?;
-  let reader = io::BufReader::new(file);
-  let mut hands = Vec::new();
-
-  for line in reader.lines() {
-    let line = line?;
-    let parts: Vec<&str> = line.split_whitespace().collect();
-    if parts.len() == 2 {
-      let cards = parts[0].to_string();
-      let bid = parts[1].parse().unwrap();
-      hands.push(Hand {
-        cards,
-        bid,
-        hand_type: HandType::HighCard,
-      }); // Placeholder type
-    }
+const CARDS: &str = "23456789TJQKA";
+
+fn parse_hand(line: Line) -> Result<Hand, ParseError> {
+  let fields: Vec<&str> = line.text.split_whitespace().collect();
+  let [cards, bid] = fields[..] else {
+    return Err(line.expected("<cards> <bid>"));
+  };
+  if cards.chars().count() != 5 {
+    return Err(line.error(cards, "expected five cards, got"));
+  }
+  if let Some((i, card)) = cards.char_indices().find(|&(_, c)| !CARDS.contains(c)) {
+    return Err(line.error(&cards[i..i + card.len_utf8()], "invalid card"));
   }
 
-  Ok(hands)
+  Ok(Hand {
+    cards: cards.to_string(),
+    bid: line.parse(bid, "bid")?,
+    hand_type: HandType::HighCard, // Placeholder type
+  })
 }
 
 fn determine_hand_type_part1(cards: &str) -> HandType {
@@ -133,7 +157,7 @@ fn compare_hands(a: &Hand, b: &Hand, card_value: fn(char) -> u8) -> Ordering {
   }
 }
 
-fn calculate_winnings(hands: &mut [Hand]) -> u64 {
+fn calculate_winnings(hands: &[Hand]) -> u64 {
   hands
     .iter()
     .enumerate()
@@ -141,32 +165,28 @@ fn calculate_winnings(hands: &mut [Hand]) -> u64 {
     .sum()
 }
 
-fn solve_part1<P: AsRef<Path>>(input_path: P) -> io::Result<u64> {
-  let mut hands = parse_input(input_path)?;
+fn solve_part1(hands: &[Hand]) -> u64 {
+  let mut hands = hands.to_vec();
   for hand in &mut hands {
     hand.hand_type = determine_hand_type_part1(&hand.cards);
   }
   hands.sort_by(|a, b| compare_hands(a, b, card_value_part1));
-  Ok(calculate_winnings(&mut hands))
+  calculate_winnings(&hands)
 }
 
-fn solve_part2<P: AsRef<Path>>(input_path: P) -> io::Result<u64> {
-  let mut hands = parse_input(input_path)?;
+fn solve_part2(hands: &[Hand]) -> u64 {
+  let mut hands = hands.to_vec();
   for hand in &mut hands {
     hand.hand_type = determine_hand_type_part2(&hand.cards);
   }
   hands.sort_by(|a, b| compare_hands(a, b, card_value_part2));
-  Ok(calculate_winnings(&mut hands))
-}
-
-fn main() {
-  dbg!(solve_part1("input/d07_full.txt").unwrap());
-  dbg!(solve_part2("input/d07_full.txt").unwrap());
+  calculate_winnings(&hands)
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::{Part, solve_file};
 
   #[test]
   fn test_determine_hand_type_part1() {
@@ -225,25 +245,33 @@ mod tests {
 
   #[test]
   fn test_total_winnings_part1_simple() {
-    let result = solve_part1("input/d07_simple.txt").unwrap();
+    let result = solve_file::<Day07>("input/d07_simple.txt", Part::One);
     assert_eq!(result, 6440);
   }
 
   #[test]
   fn test_total_winnings_part2_simple() {
-    let result = solve_part2("input/d07_simple.txt").unwrap();
+    let result = solve_file::<Day07>("input/d07_simple.txt", Part::Two);
     assert_eq!(result, 5905);
   }
 
   #[test]
   fn test_total_winnings_part1() {
-    let result = solve_part1("input/d07_full.txt").unwrap();
+    let result = solve_file::<Day07>("input/d07_full.txt", Part::One);
     assert_eq!(result, 251058093);
   }
 
   #[test]
   fn test_total_winnings_part2() {
-    let result = solve_part2("input/d07_full.txt").unwrap();
+    let result = solve_file::<Day07>("input/d07_full.txt", Part::Two);
     assert_eq!(result, 249781879);
   }
+
+  #[test]
+  fn test_parse_rejects_malformed_hands() {
+    let error = Day07::parse("32T3K 765\nKK677\n").err().unwrap();
+    assert_eq!(error.location.unwrap().line, 2);
+    let error = Day07::parse("32T3X 765\n").err().unwrap();
+    assert_eq!(error.location.unwrap().column, 5);
+  }
 }
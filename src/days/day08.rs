@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+use crate::{budget, math};
+
+pub struct Day08 {
+  instructions: Vec<char>,
+  network: Network,
+}
+
+impl Solution for Day08 {
+  const DAY: u8 = 8;
+  const TITLE: &'static str = "Haunted Wasteland";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let (instructions, network) = parse_input(input)?;
+    Ok(Day08 {
+      instructions,
+      network,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    navigate_network(&self.instructions, &self.network).into()
+  }
+
+  fn part2(&self) -> Answer {
+    navigate_network_ghost(&self.instructions, &self.network).into()
+  }
+}
+
+type Network = HashMap<String, (String, String)>;
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Network), ParseError> {
+  let mut lines = parse::lines(input).filter(|line| !line.text.is_empty());
+  let line = lines.next().ok_or_else(|| ParseError::new("empty input"))?;
+  if let Some((i, c)) = line
+    .text
+    .char_indices()
+    .find(|&(_, c)| c != 'L' && c != 'R')
+  {
+    return Err(line.error(&line.text[i..i + c.len_utf8()], "invalid instruction"));
+  }
+  let instructions: Vec<char> = line.text.chars().collect();
+
+  let nodes: Vec<(Line, [&str; 3])> = lines
+    .map(|line| Ok((line, parse_node(line)?)))
+    .collect::<Result<_, ParseError>>()?;
+
+  let mut network = HashMap::new();
+  for &(line, [node, left, right]) in &nodes {
+    let next = (left.to_string(), right.to_string());
+    if network.insert(node.to_string(), next).is_some() {
+      return Err(line.error(node, "duplicate node"));
+    }
+  }
+  for &(line, [_, left, right]) in &nodes {
+    if let Some(unknown) = [left, right]
+      .into_iter()
+      .find(|n| !network.contains_key(*n))
+    {
+      return Err(line.error(unknown, "unknown node"));
+    }
+  }
+
+  Ok((instructions, network))
+}
+
+/// Splits `AAA = (BBB, CCC)` into its node and the nodes to the left and right.
+fn parse_node<'a>(line: Line<'a>) -> Result<[&'a str; 3], ParseError> {
+  line
+    .text
+    .split_once(" = (")
+    .and_then(|(node, next)| {
+      let (left, right) = next.strip_suffix(')')?.split_once(", ")?;
+      Some([node, left, right])
+    })
+    .filter(|names| names.iter().all(|name| !name.is_empty()))
+    .ok_or_else(|| line.expected("AAA = (BBB, CCC)"))
+}
+
+/// Steps from `AAA` to `ZZZ`, or `None` if the network lacks either node or `ZZZ` is out of
+/// reach.
+fn navigate_network(instructions: &[char], network: &Network) -> Option<usize> {
+  if !network.contains_key("ZZZ") {
+    return None;
+  }
+  steps_to_end(instructions, network, "AAA", |node| node == "ZZZ")
+}
+
+/// Steps until every ghost stands on a `Z` node at once, or `None` if some ghost never reaches
+/// one.
+///
+/// # Panics
+///
+/// If the count does not fit in a `u128`, which is not the same as there being no answer.
+fn navigate_network_ghost(instructions: &[char], network: &Network) -> Option<u128> {
+  let mut cycles: Vec<u128> = Vec::new();
+  for start_node in network.keys().filter(|k| k.ends_with('A')) {
+    let steps = steps_to_end(instructions, network, start_node, |node| {
+      node.ends_with('Z')
+    })?;
+    crate::trace!(
+      "ghost at {} reaches a Z node after {} steps",
+      start_node,
+      steps
+    );
+    cycles.push(steps as u128);
+  }
+
+  crate::debug!("{} ghosts with cycles {:?}", cycles.len(), cycles);
+  match math::lcm_all(cycles) {
+    Ok(steps) => Some(steps),
+    Err(e) => panic!("answer {}", e),
+  }
+}
+
+/// Steps from `start` to the first node that `is_end`, or `None` if `start` is not in the
+/// network or no such node is reachable.
+fn steps_to_end(
+  instructions: &[char],
+  network: &Network,
+  start: &str,
+  is_end: impl Fn(&str) -> bool,
+) -> Option<usize> {
+  // A walk that has not arrived after visiting every (node, instruction) state is in a loop.
+  let states = network.len() * instructions.len();
+  let mut current_node = network.get_key_value(start)?.0;
+  let mut steps = 0;
+
+  while !is_end(current_node) {
+    if steps >= states {
+      return None;
+    }
+    budget::tick("steps");
+    let (left, right) = &network[current_node];
+    current_node = match instructions[steps % instructions.len()] {
+      'L' => left,
+      'R' => right,
+      _ => panic!("Invalid instruction"),
+    };
+    steps += 1;
+  }
+
+  Some(steps)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+  use crate::{differential, generate};
+  use rand::Rng;
+
+  /// Instructions and, for each ghost, its `node = (left, right)` lines, as made by
+  /// [`generate::ghost_network`].
+  type Maze = (String, Vec<Vec<String>>);
+
+  /// Moves every ghost at once until they all stand on a `Z` node.
+  fn brute_force_ghosts(instructions: &[char], network: &Network) -> u128 {
+    let mut nodes: Vec<&String> = network.keys().filter(|k| k.ends_with('A')).collect();
+    let mut steps = 0;
+    while !nodes.iter().all(|node| node.ends_with('Z')) {
+      let instruction = instructions[steps % instructions.len()];
+      for node in &mut nodes {
+        let (left, right) = &network[*node];
+        *node = if instruction == 'L' { left } else { right };
+      }
+      steps += 1;
+    }
+    steps as u128
+  }
+
+  #[test]
+  fn test_ghost_cycles_match_brute_force() {
+    let parse = |(instructions, ghosts): &Maze| {
+      parse_input(&format!(
+        "{}\n\n{}",
+        instructions,
+        ghosts.concat().join("\n")
+      ))
+      .unwrap()
+    };
+    differential::check(
+      300,
+      |rng| {
+        let (instructions, ghosts) = (rng.random_range(1..=4), rng.random_range(1..=4));
+        generate::ghost_network(rng, instructions, ghosts, 4)
+      },
+      |(instructions, ghosts)| {
+        differential::without_each(ghosts)
+          .into_iter()
+          .filter(|ghosts| !ghosts.is_empty())
+          .map(|ghosts| (instructions.clone(), ghosts))
+          .collect()
+      },
+      |maze| {
+        let (instructions, network) = parse(maze);
+        navigate_network_ghost(&instructions, &network).unwrap()
+      },
+      |maze| {
+        let (instructions, network) = parse(maze);
+        brute_force_ghosts(&instructions, &network)
+      },
+    );
+  }
+
+  #[test]
+  fn test_unreachable_end() {
+    let (instructions, network) = parse_input("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    assert_eq!(navigate_network(&instructions, &network), None);
+    assert_eq!(navigate_network_ghost(&instructions, &network), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "answer overflows u128")]
+  fn test_ghost_steps_beyond_u128() {
+    // One ghost per prime up to 103, which reaches its Z node after that many steps.
+    let primes = (2..=103u32).filter(|&n| (2..n).all(|d| n % d != 0));
+    let mut lines = vec!["L\n".to_string()];
+    for prime in primes {
+      let name = |step| match step {
+        0 => format!("{}A", prime),
+        step if step == prime => format!("{}Z", prime),
+        step => format!("{}-{}", prime, step),
+      };
+      for step in 0..=prime {
+        let next = name((step + 1).min(prime));
+        lines.push(format!("{} = ({}, {})", name(step), next, next));
+      }
+    }
+    let (instructions, network) = parse_input(&lines.join("\n")).unwrap();
+    navigate_network_ghost(&instructions, &network);
+  }
+
+  #[test]
+  fn test_example_1() {
+    assert_eq!(solve_file::<Day08>("input/d08_simple1.txt", Part::One), 2);
+  }
+
+  #[test]
+  fn test_example_2() {
+    assert_eq!(solve_file::<Day08>("input/d08_simple2.txt", Part::One), 6);
+  }
+
+  #[test]
+  fn test_example_3() {
+    assert_eq!(solve_file::<Day08>("input/d08_simple3.txt", Part::Two), 6);
+  }
+
+  #[test]
+  fn test_full_pt1() {
+    assert_eq!(solve_file::<Day08>("input/d08_full.txt", Part::One), 14681);
+  }
+
+  #[test]
+  fn test_full_pt2() {
+    assert_eq!(
+      solve_file::<Day08>("input/d08_full.txt", Part::Two),
+      14321394058031
+    );
+  }
+}
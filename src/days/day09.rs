@@ -0,0 +1,119 @@
+use crate::{Answer, ParseError, Solution, parse};
+
+pub struct Day09 {
+  sequences: Vec<Vec<i64>>,
+}
+
+impl Solution for Day09 {
+  const DAY: u8 = 9;
+  const TITLE: &'static str = "Mirage Maintenance";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let sequences = parse::lines(input)
+      .map(|line| {
+        if line.text.trim().is_empty() {
+          return Err(line.error(line.text, "empty sequence"));
+        }
+        line
+          .text
+          .split_whitespace()
+          .map(|s| line.parse(s, "number"))
+          .collect()
+      })
+      .collect::<Result<_, _>>()?;
+    Ok(Day09 { sequences })
+  }
+
+  fn part1(&self) -> Answer {
+    self
+      .sequences
+      .iter()
+      .try_fold(0i64, |sum, numbers| {
+        sum.checked_add(extrapolate_next_value(numbers)?)
+      })
+      .into()
+  }
+
+  fn part2(&self) -> Answer {
+    self
+      .sequences
+      .iter()
+      .try_fold(0i64, |sum, numbers| {
+        sum.checked_add(extrapolate_previous_value(numbers)?)
+      })
+      .into()
+  }
+}
+
+/// The value after the end of `sequence`, or `None` if a difference or the value overflows.
+fn extrapolate_next_value(sequence: &[i64]) -> Option<i64> {
+  if sequence.iter().all(|&x| x == 0) {
+    return Some(0);
+  }
+  sequence
+    .last()?
+    .checked_add(extrapolate_next_value(&differences(sequence)?)?)
+}
+
+/// The value before the start of `sequence`, or `None` if a difference or the value overflows.
+fn extrapolate_previous_value(sequence: &[i64]) -> Option<i64> {
+  if sequence.iter().all(|&x| x == 0) {
+    return Some(0);
+  }
+  sequence
+    .first()?
+    .checked_sub(extrapolate_previous_value(&differences(sequence)?)?)
+}
+
+fn differences(sequence: &[i64]) -> Option<Vec<i64>> {
+  sequence
+    .windows(2)
+    .map(|w| w[1].checked_sub(w[0]))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_extrapolate_next_value() {
+    assert_eq!(extrapolate_next_value(&[0, 3, 6, 9, 12, 15]), Some(18));
+    assert_eq!(extrapolate_next_value(&[1, 3, 6, 10, 15, 21]), Some(28));
+    assert_eq!(extrapolate_next_value(&[10, 13, 16, 21, 30, 45]), Some(68));
+  }
+
+  #[test]
+  fn test_extrapolate_previous_value() {
+    assert_eq!(
+      extrapolate_previous_value(&[10, 13, 16, 21, 30, 45]),
+      Some(5)
+    );
+    assert_eq!(extrapolate_previous_value(&[0, 3, 6, 9, 12, 15]), Some(-3));
+    assert_eq!(extrapolate_previous_value(&[1, 3, 6, 10, 15, 21]), Some(0));
+  }
+
+  #[test]
+  fn test_solve_simple_part1() {
+    assert_eq!(solve_file::<Day09>("input/d09_simple.txt", Part::One), 114);
+  }
+
+  #[test]
+  fn test_solve_full_part1() {
+    assert_eq!(
+      solve_file::<Day09>("input/d09_full.txt", Part::One),
+      1696140818
+    );
+  }
+
+  #[test]
+  fn test_solve_simple_part2() {
+    assert_eq!(solve_file::<Day09>("input/d09_simple.txt", Part::Two), 2);
+  }
+
+  #[test]
+  fn test_solve_full_part2() {
+    assert_eq!(solve_file::<Day09>("input/d09_full.txt", Part::Two), 1152);
+  }
+}
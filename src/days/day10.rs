@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use crate::geometry::Direction;
+use crate::grid::{Coord, Grid};
+use crate::{Answer, ParseError, Solution, parse, search};
+
+pub struct Day10 {
+  /// The tiles, with the start tile replaced by the pipe hidden under it.
+  grid: Grid<Pipe>,
+  start: Coord,
+}
+
+impl Solution for Day10 {
+  const DAY: u8 = 10;
+  const TITLE: &'static str = "Pipe Maze";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let mut grid = parse::grid(parse::lines(input), "pipe", Pipe::from_char)?;
+    let start = find_start(&grid)?;
+    grid[start] = determine_start_pipe(&grid, start)
+      .ok_or_else(|| ParseError::new("the start tile does not connect to exactly two pipes"))?;
+    Ok(Day10 { grid, start })
+  }
+
+  fn part1(&self) -> Answer {
+    let distances = loop_distances(&self.grid, self.start);
+    distances.values().max().copied().into()
+  }
+
+  fn part2(&self) -> Answer {
+    let distances = loop_distances(&self.grid, self.start);
+    let on_loop = Grid::from_fn(self.grid.width(), self.grid.height(), |coord| {
+      distances.contains_key(&coord)
+    });
+    count_enclosed_tiles(&self.grid, &on_loop).into()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pipe {
+  Vertical,
+  Horizontal,
+  NorthEast,
+  NorthWest,
+  SouthWest,
+  SouthEast,
+  Ground,
+  Start,
+}
+
+impl Pipe {
+  fn from_char(c: char) -> Option<Self> {
+    match c {
+      '|' => Some(Pipe::Vertical),
+      '-' => Some(Pipe::Horizontal),
+      'L' => Some(Pipe::NorthEast),
+      'J' => Some(Pipe::NorthWest),
+      '7' => Some(Pipe::SouthWest),
+      'F' => Some(Pipe::SouthEast),
+      '.' => Some(Pipe::Ground),
+      'S' => Some(Pipe::Start),
+      _ => None,
+    }
+  }
+
+  /// Directions of the tiles this pipe connects to.
+  fn connections(self) -> &'static [Direction] {
+    use Direction::*;
+
+    match self {
+      Pipe::Vertical => &[Up, Down],
+      Pipe::Horizontal => &[Left, Right],
+      Pipe::NorthEast => &[Up, Right],
+      Pipe::NorthWest => &[Up, Left],
+      Pipe::SouthWest => &[Down, Left],
+      Pipe::SouthEast => &[Down, Right],
+      Pipe::Ground | Pipe::Start => &[],
+    }
+  }
+}
+
+fn find_start(grid: &Grid<Pipe>) -> Result<Coord, ParseError> {
+  let mut starts = grid.find_all(&Pipe::Start);
+  match (starts.next(), starts.next()) {
+    (Some(start), None) => Ok(start),
+    (None, _) => Err(ParseError::new("no start tile")),
+    (Some(_), Some(_)) => Err(ParseError::new("more than one start tile")),
+  }
+}
+
+fn determine_start_pipe(grid: &Grid<Pipe>, start: Coord) -> Option<Pipe> {
+  let connects = |direction: Direction| {
+    grid
+      .neighbor(start, direction)
+      .is_some_and(|next| grid[next].connections().contains(&direction.reverse()))
+  };
+
+  match (
+    connects(Direction::Up),
+    connects(Direction::Down),
+    connects(Direction::Left),
+    connects(Direction::Right),
+  ) {
+    (true, true, false, false) => Some(Pipe::Vertical),
+    (false, false, true, true) => Some(Pipe::Horizontal),
+    (true, false, true, false) => Some(Pipe::NorthWest),
+    (true, false, false, true) => Some(Pipe::NorthEast),
+    (false, true, true, false) => Some(Pipe::SouthWest),
+    (false, true, false, true) => Some(Pipe::SouthEast),
+    _ => None,
+  }
+}
+
+/// Distance along the loop from `start` to each of its tiles, the shorter way round.
+fn loop_distances(grid: &Grid<Pipe>, start: Coord) -> HashMap<Coord, usize> {
+  search::bfs_distances(start, |&coord| {
+    grid[coord]
+      .connections()
+      .iter()
+      .filter_map(move |&direction| grid.neighbor(coord, direction))
+      .filter(|&next| grid[next] != Pipe::Ground)
+  })
+}
+
+fn count_enclosed_tiles(grid: &Grid<Pipe>, on_loop: &Grid<bool>) -> usize {
+  let mut count = 0;
+
+  for y in 0..grid.height() {
+    let mut inside = false;
+    let mut last_bend = None;
+
+    for x in 0..grid.width() {
+      let coord = Coord::new(x, y);
+      if on_loop[coord] {
+        let pipe = grid[coord];
+        match pipe {
+          Pipe::Vertical => inside = !inside,
+          Pipe::NorthEast | Pipe::SouthEast => last_bend = Some(pipe),
+          Pipe::NorthWest | Pipe::SouthWest => {
+            if let Some(last) = last_bend
+              && ((last == Pipe::SouthEast && pipe == Pipe::NorthWest)
+                || (last == Pipe::NorthEast && pipe == Pipe::SouthWest))
+            {
+              inside = !inside;
+            }
+            last_bend = None;
+          }
+          _ => {}
+        }
+      } else if inside {
+        count += 1;
+      }
+    }
+  }
+
+  count
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple1() {
+    assert_eq!(solve_file::<Day10>("input/d10_simple1.txt", Part::One), 4);
+  }
+
+  #[test]
+  fn test_part1_simple2() {
+    assert_eq!(solve_file::<Day10>("input/d10_simple2.txt", Part::One), 8);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day10>("input/d10_full.txt", Part::One), 6927);
+  }
+
+  #[test]
+  fn test_part2_simple3() {
+    assert_eq!(solve_file::<Day10>("input/d10_simple3.txt", Part::Two), 4);
+  }
+
+  #[test]
+  fn test_part2_simple4() {
+    assert_eq!(solve_file::<Day10>("input/d10_simple4.txt", Part::Two), 8);
+  }
+
+  #[test]
+  fn test_part2_simple5() {
+    assert_eq!(solve_file::<Day10>("input/d10_simple5.txt", Part::Two), 10);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day10>("input/d10_full.txt", Part::Two), 467);
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    let error = Day10::parse("S-7\n|X|\nL-J\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 2: invalid pipe `X`");
+    assert!(Day10::parse("...\n...\n").is_err());
+  }
+}
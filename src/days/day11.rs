@@ -0,0 +1,111 @@
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::{Answer, ParseError, Solution, parse};
+
+pub struct Day11 {
+  /// Whether each cell holds a galaxy.
+  universe: Grid<bool>,
+  /// How many times larger each empty row and column becomes in part 2.
+  expansion: i64,
+}
+
+impl Solution for Day11 {
+  const DAY: u8 = 11;
+  const TITLE: &'static str = "Cosmic Expansion";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day11 {
+      universe: parse::grid(parse::lines(input), "tile", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+      })?,
+      expansion: 1000000,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    sum_of_distances(&self.universe, 2).into()
+  }
+
+  fn part2(&self) -> Answer {
+    sum_of_distances(&self.universe, self.expansion).into()
+  }
+
+  fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+    match name {
+      "expansion" => {
+        self.expansion = value
+          .parse()
+          .ok()
+          .filter(|&expansion| expansion > 0)
+          .ok_or_else(|| format!("Invalid expansion: {}", value))?;
+        Ok(())
+      }
+      _ => Err(format!("Day 11 has no parameter {}", name)),
+    }
+  }
+}
+
+fn sum_of_distances(universe: &Grid<bool>, expansion_factor: i64) -> i64 {
+  let rows = expanded_positions(
+    universe.rows().map(|row| !row.contains(&true)),
+    expansion_factor,
+  );
+  let cols = expanded_positions(
+    (0..universe.width()).map(|x| !universe.column(x).any(|&galaxy| galaxy)),
+    expansion_factor,
+  );
+
+  let galaxies: Vec<Point2> = universe
+    .find_all(&true)
+    .map(|galaxy| Point2::new(cols[galaxy.x], rows[galaxy.y]))
+    .collect();
+
+  let mut total_distance = 0;
+  for (i, a) in galaxies.iter().enumerate() {
+    for b in &galaxies[i + 1..] {
+      total_distance += a.manhattan(*b);
+    }
+  }
+  total_distance
+}
+
+/// Where each row or column ends up once the empty ones before it have grown to
+/// `expansion_factor` times their size.
+fn expanded_positions(empty: impl Iterator<Item = bool>, expansion_factor: i64) -> Vec<i64> {
+  empty
+    .scan(0, |position, empty| {
+      let current = *position;
+      *position += if empty { expansion_factor } else { 1 };
+      Some(current)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day11>("input/d11_simple.txt", Part::One), 374);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(
+      solve_file::<Day11>("input/d11_full.txt", Part::One),
+      9545480
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day11>("input/d11_full.txt", Part::Two),
+      406725732046
+    );
+  }
+}
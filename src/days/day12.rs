@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day12 {
+  rows: Vec<(String, Vec<usize>)>,
+}
+
+impl Solution for Day12 {
+  const DAY: u8 = 12;
+  const TITLE: &'static str = "Hot Springs";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day12 {
+      rows: parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    self
+      .rows
+      .iter()
+      .map(|(springs, groups)| count_arrangements(springs, groups))
+      .sum::<usize>()
+      .into()
+  }
+
+  fn part2(&self) -> Answer {
+    self
+      .rows
+      .iter()
+      .map(|(springs, groups)| {
+        let (springs, groups) = unfold(springs, groups);
+        count_arrangements(&springs, &groups)
+      })
+      .sum::<usize>()
+      .into()
+  }
+}
+
+fn count_arrangements(springs: &str, groups: &[usize]) -> usize {
+  let mut memo = HashMap::new();
+  count_arrangements_recursive(springs, groups, 0, 0, 0, &mut memo)
+}
+
+fn parse_line(line: Line) -> Result<(String, Vec<usize>), ParseError> {
+  let fields: Vec<&str> = line.text.split_whitespace().collect();
+  let [springs, groups] = fields[..] else {
+    return Err(line.expected("<springs> <groups>"));
+  };
+  if let Some((i, c)) = springs
+    .char_indices()
+    .find(|&(_, c)| !matches!(c, '.' | '#' | '?'))
+  {
+    return Err(line.error(&springs[i..i + c.len_utf8()], "invalid spring"));
+  }
+  let groups: Vec<usize> = groups
+    .split(',')
+    .map(|n| line.parse(n, "group size"))
+    .collect::<Result<_, _>>()?;
+  Ok((springs.to_string(), groups))
+}
+
+fn unfold(springs: &str, groups: &[usize]) -> (String, Vec<usize>) {
+  ([springs; 5].join("?"), groups.repeat(5))
+}
+
+fn count_arrangements_recursive(
+  springs: &str,
+  groups: &[usize],
+  spring_index: usize,
+  group_index: usize,
+  current_group_size: usize,
+  memo: &mut HashMap<(usize, usize, usize), usize>,
+) -> usize {
+  let key = (spring_index, group_index, current_group_size);
+  if let Some(&count) = memo.get(&key) {
+    return count;
+  }
+
+  if spring_index == springs.len() {
+    let finished = (group_index == groups.len() && current_group_size == 0)
+      || (group_index + 1 == groups.len() && groups[group_index] == current_group_size);
+    return usize::from(finished);
+  }
+
+  let mut count = 0;
+  let spring = springs.chars().nth(spring_index).unwrap();
+
+  if spring == '.' || spring == '?' {
+    if current_group_size == 0 {
+      count +=
+        count_arrangements_recursive(springs, groups, spring_index + 1, group_index, 0, memo);
+    } else if group_index < groups.len() && groups[group_index] == current_group_size {
+      count +=
+        count_arrangements_recursive(springs, groups, spring_index + 1, group_index + 1, 0, memo);
+    }
+  }
+
+  if spring == '#' || spring == '?' {
+    count += count_arrangements_recursive(
+      springs,
+      groups,
+      spring_index + 1,
+      group_index,
+      current_group_size + 1,
+      memo,
+    );
+  }
+
+  memo.insert(key, count);
+  count
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::differential::{self, without_each};
+  use crate::generate;
+  use crate::{Part, solve_file};
+  use rand::Rng;
+
+  /// Sizes of the runs of damaged springs in a row without unknowns.
+  fn groups_of(springs: &str) -> Vec<usize> {
+    springs
+      .split('.')
+      .filter(|group| !group.is_empty())
+      .map(str::len)
+      .collect()
+  }
+
+  /// Tries every way of filling in the unknown springs.
+  fn brute_force_arrangements(springs: &str, groups: &[usize]) -> usize {
+    let unknown: Vec<usize> = springs.match_indices('?').map(|(i, _)| i).collect();
+    (0..1u32 << unknown.len())
+      .filter(|mask| {
+        let mut row = springs.as_bytes().to_vec();
+        for (bit, &i) in unknown.iter().enumerate() {
+          row[i] = if mask >> bit & 1 == 1 { b'#' } else { b'.' };
+        }
+        groups_of(std::str::from_utf8(&row).unwrap()) == groups
+      })
+      .count()
+  }
+
+  #[test]
+  fn test_arrangements_match_brute_force() {
+    differential::check(
+      500,
+      |rng| {
+        let length = rng.random_range(1..=12);
+        generate::spring_row(rng, length)
+      },
+      |(springs, groups)| {
+        let mut smaller: Vec<(String, Vec<usize>)> = (0..springs.len())
+          .map(|i| {
+            let mut springs = springs.clone();
+            springs.remove(i);
+            (springs, groups.clone())
+          })
+          .collect();
+        smaller.extend(
+          without_each(groups)
+            .into_iter()
+            .map(|groups| (springs.clone(), groups)),
+        );
+        smaller
+      },
+      |(springs, groups)| count_arrangements(springs, groups),
+      |(springs, groups)| brute_force_arrangements(springs, groups),
+    );
+  }
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day12>("input/d12_simple.txt", Part::One), 21);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day12>("input/d12_full.txt", Part::One), 7622);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(
+      solve_file::<Day12>("input/d12_simple.txt", Part::Two),
+      525152
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day12>("input/d12_full.txt", Part::Two),
+      4964259839627
+    );
+  }
+}
@@ -0,0 +1,87 @@
+use crate::grid::Grid;
+use crate::{Answer, ParseError, Solution, parse};
+
+type Pattern = Grid<char>;
+
+pub struct Day13 {
+  patterns: Vec<Pattern>,
+}
+
+impl Solution for Day13 {
+  const DAY: u8 = 13;
+  const TITLE: &'static str = "Point of Incidence";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let patterns = parse::sections(input)
+      .into_iter()
+      .map(|pattern| parse::grid(pattern, "tile", |c| matches!(c, '.' | '#').then_some(c)))
+      .collect::<Result<_, _>>()?;
+    Ok(Day13 { patterns })
+  }
+
+  fn part1(&self) -> Answer {
+    summarize_patterns(&self.patterns, 0).into()
+  }
+
+  fn part2(&self) -> Answer {
+    summarize_patterns(&self.patterns, 1).into()
+  }
+}
+
+/// Summarizes each pattern's reflections that are off by exactly `smudges` cells.
+fn summarize_patterns(patterns: &[Pattern], smudges: usize) -> usize {
+  patterns
+    .iter()
+    .map(|pattern| {
+      100 * reflection_rows(pattern, smudges) + reflection_rows(&pattern.transpose(), smudges)
+    })
+    .sum()
+}
+
+/// The sum, over every horizontal line of reflection, of the number of rows above it.
+fn reflection_rows(pattern: &Pattern, smudges: usize) -> usize {
+  (1..pattern.height())
+    .filter(|&line| is_reflection(pattern, line, smudges))
+    .sum()
+}
+
+fn is_reflection(pattern: &Pattern, line: usize, smudges: usize) -> bool {
+  let mut differences = 0;
+
+  for i in 0..line.min(pattern.height() - line) {
+    let above = pattern.row(line - i - 1);
+    let below = pattern.row(line + i);
+    differences += above.iter().zip(below).filter(|(a, b)| a != b).count();
+    if differences > smudges {
+      return false;
+    }
+  }
+
+  differences == smudges
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day13>("input/d13_simple.txt", Part::One), 405);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day13>("input/d13_full.txt", Part::One), 31739);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day13>("input/d13_simple.txt", Part::Two), 400);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day13>("input/d13_full.txt", Part::Two), 31539);
+  }
+}
@@ -0,0 +1,95 @@
+use crate::grid::{Coord, Grid};
+use crate::{Answer, ParseError, Solution, cycle, parse};
+
+pub struct Day14 {
+  grid: Grid<char>,
+}
+
+impl Solution for Day14 {
+  const DAY: u8 = 14;
+  const TITLE: &'static str = "Parabolic Reflector Dish";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day14 {
+      grid: parse::grid(parse::lines(input), "tile", |c| {
+        matches!(c, '.' | '#' | 'O').then_some(c)
+      })?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    let mut grid = self.grid.clone();
+    tilt_north(&mut grid);
+    calculate_load(&grid).into()
+  }
+
+  fn part2(&self) -> Answer {
+    let grid = cycle::state_at(self.grid.clone(), 1000000000, spin_cycle);
+    calculate_load(&grid).into()
+  }
+}
+
+fn tilt_north(grid: &mut Grid<char>) {
+  for x in 0..grid.width() {
+    let mut write_pos = 0;
+    for read_pos in 0..grid.height() {
+      match grid[Coord::new(x, read_pos)] {
+        'O' => {
+          grid[Coord::new(x, read_pos)] = '.';
+          grid[Coord::new(x, write_pos)] = 'O';
+          write_pos += 1;
+        }
+        '#' => {
+          write_pos = read_pos + 1;
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+/// Tilts north, west, south and east in turn. Turning the grid clockwise after each tilt brings
+/// the next side to the north, and four turns restore the original orientation.
+fn spin_cycle(grid: &Grid<char>) -> Grid<char> {
+  let mut grid = grid.clone();
+  for _ in 0..4 {
+    tilt_north(&mut grid);
+    grid = grid.rotate_clockwise();
+  }
+  grid
+}
+
+fn calculate_load(grid: &Grid<char>) -> usize {
+  let rows = grid.height();
+  grid
+    .rows()
+    .enumerate()
+    .map(|(i, row)| row.iter().filter(|&&c| c == 'O').count() * (rows - i))
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day14>("input/d14_simple.txt", Part::One), 136);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day14>("input/d14_full.txt", Part::One), 107951);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day14>("input/d14_simple.txt", Part::Two), 64);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day14>("input/d14_full.txt", Part::Two), 95736);
+  }
+}
@@ -0,0 +1,152 @@
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day15 {
+  steps: Vec<Step>,
+}
+
+impl Solution for Day15 {
+  const DAY: u8 = 15;
+  const TITLE: &'static str = "Lens Library";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let mut steps = Vec::new();
+    for line in parse::lines(input) {
+      for step in line.text.split(',').filter(|step| !step.is_empty()) {
+        steps.push(parse_step(line, step)?);
+      }
+    }
+    Ok(Day15 { steps })
+  }
+
+  fn part1(&self) -> Answer {
+    self
+      .steps
+      .iter()
+      .map(|step| hash(&step.text) as u32)
+      .sum::<u32>()
+      .into()
+  }
+
+  fn part2(&self) -> Answer {
+    let mut boxes: Vec<Vec<Lens>> = vec![Vec::new(); 256];
+    self
+      .steps
+      .iter()
+      .for_each(|step| process_step(&mut boxes, step));
+    calculate_focusing_power(&boxes).into()
+  }
+}
+
+fn hash(s: &str) -> usize {
+  s.bytes().fold(0, |acc, b| (acc + b as usize) * 17 % 256)
+}
+
+struct Step {
+  text: String,
+  label: String,
+  operation: Operation,
+}
+
+enum Operation {
+  Remove,
+  Insert(u32),
+}
+
+fn parse_step(line: Line, text: &str) -> Result<Step, ParseError> {
+  let (label, operation) = match text.find(['=', '-']) {
+    Some(i) => text.split_at(i),
+    None => return Err(line.error(text, "expected `<label>=<focal length>` or `<label>-`, got")),
+  };
+  let operation = match operation {
+    "-" => Operation::Remove,
+    _ => match operation.strip_prefix('=') {
+      Some(focal_length) => Operation::Insert(line.parse(focal_length, "focal length")?),
+      None => return Err(line.error(operation, "invalid operation")),
+    },
+  };
+
+  Ok(Step {
+    text: text.to_string(),
+    label: label.to_string(),
+    operation,
+  })
+}
+
+#[derive(Debug, Clone)]
+struct Lens {
+  label: String,
+  focal_length: u32,
+}
+
+fn process_step(boxes: &mut [Vec<Lens>], step: &Step) {
+  let label = &step.label;
+  let box_number = hash(label);
+
+  match step.operation {
+    Operation::Remove => {
+      if let Some(pos) = boxes[box_number]
+        .iter()
+        .position(|lens| lens.label == *label)
+      {
+        boxes[box_number].remove(pos);
+      }
+    }
+    Operation::Insert(focal_length) => {
+      if let Some(pos) = boxes[box_number]
+        .iter()
+        .position(|lens| lens.label == *label)
+      {
+        boxes[box_number][pos].focal_length = focal_length;
+      } else {
+        boxes[box_number].push(Lens {
+          label: label.clone(),
+          focal_length,
+        });
+      }
+    }
+  }
+}
+
+fn calculate_focusing_power(boxes: &[Vec<Lens>]) -> u128 {
+  boxes
+    .iter()
+    .enumerate()
+    .flat_map(|(box_num, lenses)| {
+      lenses.iter().enumerate().map(move |(slot, lens)| {
+        (box_num as u128 + 1) * (slot as u128 + 1) * u128::from(lens.focal_length)
+      })
+    })
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_hash() {
+    assert_eq!(hash("HASH"), 52);
+  }
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day15>("input/d15_simple.txt", Part::One), 1320);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day15>("input/d15_full.txt", Part::One), 516070);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day15>("input/d15_simple.txt", Part::Two), 145);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day15>("input/d15_full.txt", Part::Two), 244981);
+  }
+}
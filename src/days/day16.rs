@@ -0,0 +1,117 @@
+use crate::geometry::Direction;
+use crate::grid::{Coord, Grid};
+use crate::{Answer, ParseError, Solution, parse};
+
+pub struct Day16 {
+  grid: Grid<char>,
+}
+
+impl Solution for Day16 {
+  const DAY: u8 = 16;
+  const TITLE: &'static str = "The Floor Will Be Lava";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day16 {
+      grid: parse::grid(parse::lines(input), "tile", |c| {
+        matches!(c, '.' | '/' | '\\' | '|' | '-').then_some(c)
+      })?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    simulate_beam(&self.grid, Coord::new(0, 0), Direction::Right).into()
+  }
+
+  fn part2(&self) -> Answer {
+    max_energized(&self.grid).into()
+  }
+}
+
+/// A distinct bit per direction, for recording which beams have passed a tile.
+fn bit(direction: Direction) -> u8 {
+  1 << direction as u8
+}
+
+fn max_energized(grid: &Grid<char>) -> usize {
+  let (width, height) = (grid.width(), grid.height());
+
+  let vertical = (0..width).flat_map(|x| {
+    [
+      (Coord::new(x, 0), Direction::Down),
+      (Coord::new(x, height - 1), Direction::Up),
+    ]
+  });
+  let horizontal = (0..height).flat_map(|y| {
+    [
+      (Coord::new(0, y), Direction::Right),
+      (Coord::new(width - 1, y), Direction::Left),
+    ]
+  });
+
+  vertical
+    .chain(horizontal)
+    .map(|(start, direction)| simulate_beam(grid, start, direction))
+    .max()
+    .unwrap_or(0)
+}
+
+/// Number of tiles energized by a beam entering `start` heading in `direction`.
+fn simulate_beam(grid: &Grid<char>, start: Coord, direction: Direction) -> usize {
+  let mut beams = vec![(start, direction)];
+  let mut visited = Grid::new(grid.width(), grid.height(), 0u8);
+
+  while let Some((position, direction)) = beams.pop() {
+    if visited[position] & bit(direction) != 0 {
+      continue;
+    }
+    visited[position] |= bit(direction);
+
+    let (next, split) = deflect(grid[position], direction);
+    for direction in [Some(next), split].into_iter().flatten() {
+      if let Some(next_position) = grid.neighbor(position, direction) {
+        beams.push((next_position, direction));
+      }
+    }
+  }
+
+  visited.iter().filter(|&(_, &seen)| seen != 0).count()
+}
+
+/// The directions a beam heading in `direction` leaves `tile` in: a second one when it is split.
+fn deflect(tile: char, direction: Direction) -> (Direction, Option<Direction>) {
+  match tile {
+    '/' if direction.is_vertical() => (direction.turn_right(), None),
+    '/' => (direction.turn_left(), None),
+    '\\' if direction.is_vertical() => (direction.turn_left(), None),
+    '\\' => (direction.turn_right(), None),
+    '|' if !direction.is_vertical() => (Direction::Up, Some(Direction::Down)),
+    '-' if direction.is_vertical() => (Direction::Left, Some(Direction::Right)),
+    _ => (direction, None),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day16>("input/d16_simple.txt", Part::One), 46);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day16>("input/d16_full.txt", Part::One), 7060);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day16>("input/d16_simple.txt", Part::Two), 51);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day16>("input/d16_full.txt", Part::Two), 7493);
+  }
+}
@@ -0,0 +1,121 @@
+use crate::geometry::Direction;
+use crate::grid::{Coord, Grid};
+use crate::{Answer, ParseError, Solution, parse, search};
+
+pub struct Day17 {
+  grid: Grid<i32>,
+}
+
+impl Solution for Day17 {
+  const DAY: u8 = 17;
+  const TITLE: &'static str = "Clumsy Crucible";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day17 {
+      grid: parse_input(input)?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    min_heat_loss(&self.grid, 0, 3).into()
+  }
+
+  fn part2(&self) -> Answer {
+    min_heat_loss(&self.grid, 4, 10).into()
+  }
+}
+
+/// Where the crucible is and how it got there, which decides where it may go next.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+  position: Coord,
+  /// `None` before the first move.
+  direction: Option<Direction>,
+  /// Blocks moved in a straight line in `direction`.
+  steps: usize,
+}
+
+fn parse_input(input: &str) -> Result<Grid<i32>, ParseError> {
+  parse::grid(parse::lines(input), "heat loss", |c| {
+    c.to_digit(10).map(|digit| digit as i32)
+  })
+}
+
+/// Minimum heat loss from the top-left to the bottom-right block, or `None` if no route obeys
+/// the crucible's step limits.
+fn min_heat_loss(grid: &Grid<i32>, min_steps: usize, max_steps: usize) -> Option<i32> {
+  let end = Coord::new(grid.width() - 1, grid.height() - 1);
+  let start = State {
+    position: Coord::new(0, 0),
+    direction: None,
+    steps: 0,
+  };
+
+  let moves = |&state: &State| {
+    let directions = match state.direction {
+      None => vec![Direction::Right, Direction::Down],
+      Some(direction) if state.steps < min_steps => vec![direction],
+      Some(direction) => vec![direction.turn_left(), direction.turn_right(), direction],
+    };
+    directions.into_iter().filter_map(move |direction| {
+      let steps = if Some(direction) == state.direction {
+        state.steps + 1
+      } else {
+        1
+      };
+      let position = grid.neighbor(state.position, direction)?;
+      (steps <= max_steps).then(|| {
+        let next = State {
+          position,
+          direction: Some(direction),
+          steps,
+        };
+        (next, grid[position])
+      })
+    })
+  };
+  // Every block loses at least `least`, so that much per block left never overestimates. With
+  // blocks that lose nothing the estimate is 0, and the search is a plain Dijkstra.
+  let least = grid.iter().map(|(_, &loss)| loss).min().unwrap_or(0);
+  let heuristic = |state: &State| {
+    (end.x.abs_diff(state.position.x) + end.y.abs_diff(state.position.y)) as i32 * least
+  };
+
+  search::astar(start, moves, heuristic, |state| {
+    state.position == end && state.steps >= min_steps
+  })
+  .map(|path| path.cost)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day17>("input/d17_simple.txt", Part::One), 102);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day17>("input/d17_full.txt", Part::One), 1246);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(solve_file::<Day17>("input/d17_simple.txt", Part::Two), 94);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day17>("input/d17_full.txt", Part::Two), 1389);
+  }
+
+  #[test]
+  fn test_blocks_that_lose_nothing() {
+    // The cheapest route takes the long way round over the 0s.
+    let grid = parse_input("9009\n0210\n0001\n").unwrap();
+    assert_eq!(min_heat_loss(&grid, 0, 3), Some(1));
+  }
+}
@@ -0,0 +1,225 @@
+use crate::geometry::{Direction, Point2};
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day18 {
+  dig_plan: Vec<Instruction>,
+  color_plan: Vec<Instruction>,
+}
+
+impl Solution for Day18 {
+  const DAY: u8 = 18;
+  const TITLE: &'static str = "Lavaduct Lagoon";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day18 {
+      dig_plan: parse_input(input, 1)?,
+      color_plan: parse_input(input, 2)?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    calculate_area(&self.dig_plan).into()
+  }
+
+  fn part2(&self) -> Answer {
+    calculate_area(&self.color_plan).into()
+  }
+}
+
+/// The direction encoded in the last hex digit of a color.
+fn direction_from_digit(d: u8) -> Option<Direction> {
+  match d {
+    0 => Some(Direction::Right),
+    1 => Some(Direction::Down),
+    2 => Some(Direction::Left),
+    3 => Some(Direction::Up),
+    _ => None,
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Instruction {
+  direction: Direction,
+  distance: i64,
+  #[allow(dead_code)]
+  color: String,
+}
+
+/// The longest distance a color's five hex digits can encode, and so the longest accepted in
+/// part 1 too.
+const MAX_DISTANCE: i64 = 0xfffff;
+
+fn parse_input(input: &str, part: usize) -> Result<Vec<Instruction>, ParseError> {
+  parse::lines(input)
+    .map(|line| parse_instruction(line, part))
+    .collect()
+}
+
+fn parse_instruction(line: Line, part: usize) -> Result<Instruction, ParseError> {
+  let fields: Vec<&str> = line.text.split_whitespace().collect();
+  let [direction, distance, color] = fields[..] else {
+    return Err(line.expected("<direction> <distance> (#<color>)"));
+  };
+  let hex = color
+    .strip_prefix("(#")
+    .and_then(|color| color.strip_suffix(')'))
+    .filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+    .ok_or_else(|| line.error(color, "expected a color like `(#70c710)`, got"))?;
+
+  if part == 1 {
+    Ok(Instruction {
+      direction: Direction::from_letter(line.char_at(direction)?)
+        .filter(|_| direction.len() == 1)
+        .ok_or_else(|| line.error(direction, "invalid direction"))?,
+      distance: line
+        .parse(distance, "distance")
+        .ok()
+        .filter(|distance| (0..=MAX_DISTANCE).contains(distance))
+        .ok_or_else(|| line.error(distance, "invalid distance"))?,
+      color: color.trim_matches(|c| c == '(' || c == ')').to_string(),
+    })
+  } else {
+    let distance = i64::from_str_radix(&hex[..5], 16).unwrap();
+    let direction = direction_from_digit(hex.as_bytes()[5] - b'0')
+      .ok_or_else(|| line.error(&hex[5..], "invalid direction"))?;
+    Ok(Instruction {
+      direction,
+      distance,
+      color: hex.to_string(),
+    })
+  }
+}
+
+fn calculate_area(instructions: &[Instruction]) -> i128 {
+  let mut position = Point2::ORIGIN;
+  let mut area: i128 = 0;
+  let mut perimeter: i128 = 0;
+
+  for instruction in instructions {
+    let next = position.step(instruction.direction, instruction.distance);
+
+    // Shoelace formula
+    area +=
+      i128::from(position.x) * i128::from(next.y) - i128::from(position.y) * i128::from(next.x);
+    perimeter += i128::from(instruction.distance);
+
+    position = next;
+  }
+
+  // Apply Pick's theorem: A = i + b/2 - 1
+  // Where A is the area, i is the number of interior points, and b is the number of boundary points
+  // We want i + b, which is equal to A + b/2 + 1
+  (area.abs() / 2) + (perimeter / 2) + 1
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+  use crate::{differential, generate};
+  use rand::Rng;
+
+  /// A lagoon made of side-by-side columns, each `(width, top, bottom)`, as made by
+  /// [`generate::lagoon`].
+  type Columns = Vec<(i64, i64, i64)>;
+
+  fn is_valid(columns: &Columns) -> bool {
+    !columns.is_empty()
+      && columns
+        .iter()
+        .all(|&(width, top, bottom)| width > 0 && top < bottom)
+      && columns
+        .windows(2)
+        .all(|pair| pair[0].1.max(pair[1].1) < pair[0].2.min(pair[1].2))
+  }
+
+  /// Counts the dug cells one by one: every lattice point inside or on some column.
+  fn brute_force_area(columns: &Columns) -> i64 {
+    // Each column as the rectangle `(left, right, top, bottom)` it covers.
+    let mut left = 0;
+    let rectangles: Vec<(i64, i64, i64, i64)> = columns
+      .iter()
+      .map(|&(width, top, bottom)| {
+        left += width;
+        (left - width, left, top, bottom)
+      })
+      .collect();
+    let top = rectangles.iter().map(|r| r.2).min().unwrap();
+    let bottom = rectangles.iter().map(|r| r.3).max().unwrap();
+
+    let mut area = 0;
+    for x in 0..=left {
+      for y in top..=bottom {
+        let inside = rectangles
+          .iter()
+          .any(|&(l, r, t, b)| (l..=r).contains(&x) && (t..=b).contains(&y));
+        area += i64::from(inside);
+      }
+    }
+    area
+  }
+
+  #[test]
+  fn test_area_matches_brute_force() {
+    differential::check(
+      300,
+      |rng| {
+        let columns = rng.random_range(1..=5);
+        generate::lagoon(rng, columns)
+      },
+      |columns| {
+        let mut smaller = differential::without_each(columns);
+        for i in 0..columns.len() {
+          for change in [(-1, 0, 0), (0, 1, 0), (0, 0, -1)] {
+            let mut changed = columns.clone();
+            changed[i].0 += change.0;
+            changed[i].1 += change.1;
+            changed[i].2 += change.2;
+            smaller.push(changed);
+          }
+        }
+        smaller.retain(is_valid);
+        smaller
+      },
+      |columns| calculate_area(&parse_input(&generate::dig_plan(columns, 1), 1).unwrap()),
+      |columns| i128::from(brute_force_area(columns)),
+    );
+  }
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(solve_file::<Day18>("input/d18_simple.txt", Part::One), 62);
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(solve_file::<Day18>("input/d18_full.txt", Part::One), 48652);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(
+      solve_file::<Day18>("input/d18_simple.txt", Part::Two),
+      952408144115
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day18>("input/d18_full.txt", Part::Two),
+      45757884535661
+    );
+  }
+
+  #[test]
+  fn test_parse_rejects_malformed_instructions() {
+    let error = Day18::parse("R 6 (#70c710)\nQ 5 (#0dc571)\n")
+      .err()
+      .unwrap();
+    assert_eq!(error.location.unwrap().line, 2);
+    assert!(Day18::parse("R 6 (#70c71)\n").is_err());
+    assert!(Day18::parse("R 6\n").is_err());
+  }
+}
@@ -0,0 +1,393 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day19 {
+  workflows: HashMap<String, Workflow>,
+  parts: Vec<Part>,
+}
+
+impl Solution for Day19 {
+  const DAY: u8 = 19;
+  const TITLE: &'static str = "Aplenty";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let (workflows, parts) = parse_input(input)?;
+    Ok(Day19 { workflows, parts })
+  }
+
+  fn part1(&self) -> Answer {
+    self
+      .parts
+      .iter()
+      .filter(|part| process_part(part, &self.workflows))
+      .map(|part| {
+        [part.x, part.m, part.a, part.s]
+          .map(i64::from)
+          .iter()
+          .sum::<i64>()
+      })
+      .sum::<i64>()
+      .into()
+  }
+
+  fn part2(&self) -> Answer {
+    let initial_range = PartRange {
+      x: 1..4001,
+      m: 1..4001,
+      a: 1..4001,
+      s: 1..4001,
+    };
+    count_accepted_combinations(&self.workflows, "in", initial_range).into()
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Part {
+  x: i32,
+  m: i32,
+  a: i32,
+  s: i32,
+}
+
+#[derive(Debug, Clone)]
+struct PartRange {
+  x: Range<i32>,
+  m: Range<i32>,
+  a: Range<i32>,
+  s: Range<i32>,
+}
+
+#[derive(Debug)]
+enum Condition {
+  GreaterThan(char, i32),
+  LessThan(char, i32),
+  Always,
+}
+
+#[derive(Debug)]
+struct Rule {
+  condition: Condition,
+  destination: String,
+}
+
+#[derive(Debug)]
+struct Workflow {
+  #[allow(dead_code)]
+  name: String,
+  rules: Vec<Rule>,
+}
+
+fn parse_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+  let sections = parse::sections(input);
+  let [workflow_lines, part_lines] = &sections[..] else {
+    return Err(ParseError::new(format!(
+      "expected workflows and parts separated by a blank line, got {} sections",
+      sections.len()
+    )));
+  };
+
+  let mut workflows = HashMap::new();
+  for &line in workflow_lines {
+    let workflow = parse_workflow(line)?;
+    if workflows.contains_key(&workflow.name) {
+      return Err(line.error(line.text, "duplicate workflow"));
+    }
+    workflows.insert(workflow.name.clone(), workflow);
+  }
+  if !workflows.contains_key("in") {
+    return Err(ParseError::new("no `in` workflow"));
+  }
+  for &line in workflow_lines {
+    let rules = line
+      .text
+      .split_once('{')
+      .and_then(|(_, rules)| rules.strip_suffix('}'))
+      .unwrap_or_default();
+    for rule in rules.split(',') {
+      let destination = rule
+        .split_once(':')
+        .map_or(rule, |(_, destination)| destination);
+      if destination != "A" && destination != "R" && !workflows.contains_key(destination) {
+        return Err(line.error(destination, "unknown workflow"));
+      }
+    }
+  }
+  if let Some(name) = find_cycle(&workflows) {
+    return Err(ParseError::new(format!(
+      "workflow `{}` can send a part back to itself",
+      name
+    )));
+  }
+
+  let parts = part_lines
+    .iter()
+    .map(|&line| parse_part(line))
+    .collect::<Result<_, _>>()?;
+
+  Ok((workflows, parts))
+}
+
+/// A workflow on a cycle of workflows, around which a part could be sent forever.
+fn find_cycle(workflows: &HashMap<String, Workflow>) -> Option<&str> {
+  fn visit<'a>(
+    name: &'a str,
+    workflows: &'a HashMap<String, Workflow>,
+    finished: &mut HashSet<&'a str>,
+    path: &mut HashSet<&'a str>,
+  ) -> Option<&'a str> {
+    let workflow = workflows.get(name)?;
+    if finished.contains(name) {
+      return None;
+    }
+    if !path.insert(name) {
+      return Some(name);
+    }
+    for rule in &workflow.rules {
+      if let Some(name) = visit(&rule.destination, workflows, finished, path) {
+        return Some(name);
+      }
+    }
+    path.remove(name);
+    finished.insert(name);
+    None
+  }
+
+  // Workflows whose every path is known to end in `A` or `R`, and those on the current path.
+  let mut finished = HashSet::new();
+  let mut path = HashSet::new();
+  workflows
+    .keys()
+    .find_map(|name| visit(name, workflows, &mut finished, &mut path))
+}
+
+fn parse_workflow(line: Line) -> Result<Workflow, ParseError> {
+  let (name, rules_str) = line
+    .text
+    .split_once('{')
+    .and_then(|(name, rules)| Some((name, rules.strip_suffix('}')?)))
+    .ok_or_else(|| line.expected("name{rules}"))?;
+
+  let rules = rules_str
+    .split(',')
+    .map(|rule_str| {
+      let Some((condition, destination)) = rule_str.split_once(':') else {
+        return Ok(Rule {
+          condition: Condition::Always,
+          destination: rule_str.to_string(),
+        });
+      };
+      let (category, value) = condition
+        .split_once(['<', '>'])
+        .ok_or_else(|| line.error(condition, "expected a condition like `a<2006`, got"))?;
+      if !matches!(category, "x" | "m" | "a" | "s") {
+        return Err(line.error(category, "invalid category"));
+      }
+      let category = line.char_at(category)?;
+      let value = line.parse(value, "rating")?;
+      let condition = if condition.contains('>') {
+        Condition::GreaterThan(category, value)
+      } else {
+        Condition::LessThan(category, value)
+      };
+      Ok(Rule {
+        condition,
+        destination: destination.to_string(),
+      })
+    })
+    .collect::<Result<Vec<Rule>, _>>()?;
+  if !matches!(rules.last(), Some(rule) if matches!(rule.condition, Condition::Always)) {
+    return Err(line.error(
+      rules_str,
+      "expected rules ending in one without a condition, got",
+    ));
+  }
+
+  Ok(Workflow {
+    name: name.to_string(),
+    rules,
+  })
+}
+
+fn parse_part(line: Line) -> Result<Part, ParseError> {
+  let part_str = line
+    .text
+    .strip_prefix('{')
+    .and_then(|part| part.strip_suffix('}'))
+    .ok_or_else(|| line.expected("{x=..,m=..,a=..,s=..}"))?;
+  let mut part = Part {
+    x: 0,
+    m: 0,
+    a: 0,
+    s: 0,
+  };
+  for rating in part_str.split(',') {
+    let (category, value) = rating
+      .split_once('=')
+      .ok_or_else(|| line.error(rating, "expected a rating like `x=787`, got"))?;
+    let value = line.parse(value, "rating")?;
+    match category {
+      "x" => part.x = value,
+      "m" => part.m = value,
+      "a" => part.a = value,
+      "s" => part.s = value,
+      _ => return Err(line.error(category, "invalid category")),
+    }
+  }
+  Ok(part)
+}
+
+fn process_part(part: &Part, workflows: &HashMap<String, Workflow>) -> bool {
+  let mut current_workflow = "in";
+  loop {
+    let workflow = workflows.get(current_workflow).unwrap();
+    for rule in &workflow.rules {
+      let condition_met = match rule.condition {
+        Condition::GreaterThan(category, value) => match category {
+          'x' => part.x > value,
+          'm' => part.m > value,
+          'a' => part.a > value,
+          's' => part.s > value,
+          _ => unreachable!("categories are validated while parsing"),
+        },
+        Condition::LessThan(category, value) => match category {
+          'x' => part.x < value,
+          'm' => part.m < value,
+          'a' => part.a < value,
+          's' => part.s < value,
+          _ => unreachable!("categories are validated while parsing"),
+        },
+        Condition::Always => true,
+      };
+
+      if condition_met {
+        match rule.destination.as_str() {
+          "A" => return true,
+          "R" => return false,
+          _ => {
+            current_workflow = &rule.destination;
+            break;
+          }
+        }
+      }
+    }
+  }
+}
+
+fn count_accepted_combinations(
+  workflows: &HashMap<String, Workflow>,
+  current: &str,
+  mut ranges: PartRange,
+) -> u64 {
+  match current {
+    "R" => 0,
+    "A" => {
+      ranges.x.len() as u64 * ranges.m.len() as u64 * ranges.a.len() as u64 * ranges.s.len() as u64
+    }
+    _ => {
+      let workflow = workflows.get(current).unwrap();
+      let mut total = 0;
+
+      for rule in &workflow.rules {
+        let (matching, non_matching) = split_range(&ranges, &rule.condition);
+        total += count_accepted_combinations(workflows, &rule.destination, matching);
+        ranges = non_matching;
+      }
+
+      total
+    }
+  }
+}
+
+fn split_range(range: &PartRange, condition: &Condition) -> (PartRange, PartRange) {
+  let mut matching = range.clone();
+  let mut non_matching = range.clone();
+
+  match condition {
+    Condition::GreaterThan(category, value) => {
+      let (matching_range, non_matching_range) = match category {
+        'x' => (&mut matching.x, &mut non_matching.x),
+        'm' => (&mut matching.m, &mut non_matching.m),
+        'a' => (&mut matching.a, &mut non_matching.a),
+        's' => (&mut matching.s, &mut non_matching.s),
+        _ => unreachable!("categories are validated while parsing"),
+      };
+      matching_range.start = value.saturating_add(1).max(matching_range.start);
+      non_matching_range.end = value.saturating_add(1).min(non_matching_range.end);
+    }
+    Condition::LessThan(category, value) => {
+      let (matching_range, non_matching_range) = match category {
+        'x' => (&mut matching.x, &mut non_matching.x),
+        'm' => (&mut matching.m, &mut non_matching.m),
+        'a' => (&mut matching.a, &mut non_matching.a),
+        's' => (&mut matching.s, &mut non_matching.s),
+        _ => unreachable!("categories are validated while parsing"),
+      };
+      matching_range.end = *value.min(&matching_range.end);
+      non_matching_range.start = *value.max(&non_matching_range.start);
+    }
+    Condition::Always => {
+      return (
+        range.clone(),
+        PartRange {
+          x: 0..0,
+          m: 0..0,
+          a: 0..0,
+          s: 0..0,
+        },
+      );
+    }
+  }
+
+  (matching, non_matching)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::solve_file;
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(
+      solve_file::<Day19>("input/d19_simple.txt", crate::Part::One),
+      19114
+    );
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(
+      solve_file::<Day19>("input/d19_full.txt", crate::Part::One),
+      397061
+    );
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(
+      solve_file::<Day19>("input/d19_simple.txt", crate::Part::Two),
+      167409079868000
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day19>("input/d19_full.txt", crate::Part::Two),
+      125657431183201
+    );
+  }
+
+  #[test]
+  fn test_parse_rejects_unknown_workflows() {
+    let error = Day19::parse("in{a<5:qq,A}\n\n{x=1,m=2,a=3,s=4}\n")
+      .err()
+      .unwrap();
+    let location = error.location.unwrap();
+    assert_eq!((location.line, location.column), (1, 8));
+    assert!(Day19::parse("in{A}\n\n{x=1,q=2}\n").is_err());
+  }
+}
@@ -0,0 +1,360 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{Answer, ParseError, Solution, cycle, math, parse};
+
+pub struct Day20 {
+  modules: HashMap<String, Module>,
+}
+
+impl Solution for Day20 {
+  const DAY: u8 = 20;
+  const TITLE: &'static str = "Pulse Propagation";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day20 {
+      modules: parse_input(input)?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    let mut modules = self.modules.clone();
+    let mut total_low = 0;
+    let mut total_high = 0;
+    for _ in 0..1000 {
+      let (low, high, _) = push_button(&mut modules, &HashSet::new());
+      total_low += low;
+      total_high += high;
+    }
+    (total_low * total_high).into()
+  }
+
+  fn part2(&self) -> Answer {
+    solve_part_two(&self.modules).into()
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ModuleType {
+  FlipFlop(bool),
+  Conjunction(HashMap<String, bool>),
+  Broadcaster,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Module {
+  module_type: ModuleType,
+  destinations: Vec<String>,
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+  let mut modules = HashMap::new();
+
+  for line in parse::lines(input) {
+    let (module, destinations) = line
+      .text
+      .split_once(" -> ")
+      .ok_or_else(|| line.expected("<module> -> <destinations>"))?;
+    let (name, module_type) = match module.chars().next() {
+      Some('%') => (&module[1..], ModuleType::FlipFlop(false)),
+      Some('&') => (&module[1..], ModuleType::Conjunction(HashMap::new())),
+      _ if module == "broadcaster" => ("broadcaster", ModuleType::Broadcaster),
+      _ => return Err(line.error(module, "invalid module")),
+    };
+    if name.is_empty() {
+      return Err(line.error(module, "missing module name"));
+    }
+
+    let destinations: Vec<String> = destinations.split(", ").map(String::from).collect();
+    let module = Module {
+      module_type,
+      destinations,
+    };
+    if modules.insert(name.to_string(), module).is_some() {
+      return Err(line.error(name, "duplicate module"));
+    }
+  }
+
+  // Initialize conjunction modules
+  let module_names: Vec<String> = modules.keys().cloned().collect();
+  for name in module_names {
+    let destinations = modules[&name].destinations.clone();
+    for dest in destinations {
+      if let Some(Module {
+        module_type: ModuleType::Conjunction(memory),
+        ..
+      }) = modules.get_mut(&dest)
+      {
+        memory.insert(name.clone(), false);
+      }
+    }
+  }
+
+  Ok(modules)
+}
+
+fn push_button(
+  modules: &mut HashMap<String, Module>,
+  watch_list: &HashSet<String>,
+) -> (usize, usize, HashMap<String, bool>) {
+  let mut queue = VecDeque::new();
+  queue.push_back(("button".to_string(), "broadcaster".to_string(), false));
+
+  let mut low_count = 0;
+  let mut high_count = 0;
+  let mut pulses = HashMap::new();
+
+  while let Some((from, to, pulse)) = queue.pop_front() {
+    if pulse {
+      high_count += 1;
+    } else {
+      low_count += 1;
+    }
+
+    if watch_list.contains(&from) && pulse {
+      pulses.insert(from.clone(), true);
+    }
+
+    if let Some(module) = modules.get_mut(&to) {
+      match &mut module.module_type {
+        ModuleType::FlipFlop(state) => {
+          if !pulse {
+            *state = !*state;
+            for dest in &module.destinations {
+              queue.push_back((to.clone(), dest.clone(), *state));
+            }
+          }
+        }
+        ModuleType::Conjunction(memory) => {
+          memory.insert(from, pulse);
+          let output = !memory.values().all(|&v| v);
+          for dest in &module.destinations {
+            queue.push_back((to.clone(), dest.clone(), output));
+          }
+        }
+        ModuleType::Broadcaster => {
+          for dest in &module.destinations {
+            queue.push_back((to.clone(), dest.clone(), pulse));
+          }
+        }
+      }
+    }
+  }
+
+  (low_count, high_count, pulses)
+}
+
+/// The modules that can pass pulses on to `target`, `target` included, with the broadcaster kept
+/// but only sending to them. Pressing the button on the result drives `target` exactly as on the
+/// full network.
+fn subnetwork(modules: &HashMap<String, Module>, target: &str) -> HashMap<String, Module> {
+  let mut upstream = HashSet::from([target.to_string()]);
+  let mut queue = VecDeque::from([target.to_string()]);
+  while let Some(name) = queue.pop_front() {
+    for (source, module) in modules {
+      if module.destinations.contains(&name) && upstream.insert(source.clone()) {
+        queue.push_back(source.clone());
+      }
+    }
+  }
+
+  upstream
+    .iter()
+    .map(|name| {
+      let mut module = modules[name].clone();
+      if matches!(module.module_type, ModuleType::Broadcaster) {
+        module
+          .destinations
+          .retain(|destination| upstream.contains(destination));
+      }
+      (name.clone(), module)
+    })
+    .collect()
+}
+
+/// The presses on which one module sends a high pulse.
+struct HighPulses {
+  /// Presses before `from` on which it does.
+  early: Vec<i128>,
+  /// Congruences `press ≡ residue (mod period)` giving the presses from `from` on.
+  congruences: Vec<(i128, i128)>,
+  /// The first press once its subnetwork is in its cycle.
+  from: i128,
+}
+
+impl HighPulses {
+  fn contains(&self, press: i128) -> bool {
+    if press < self.from {
+      self.early.contains(&press)
+    } else {
+      self
+        .congruences
+        .iter()
+        .any(|&(residue, period)| (press - residue) % period == 0)
+    }
+  }
+}
+
+/// The presses on which `watched` sends a high pulse.
+///
+/// The subnetwork feeding `watched` is a small counter, so its states repeat after a few
+/// thousand presses. The presses before that are listed one by one, and the rest as congruences.
+fn high_pulse_presses(modules: &HashMap<String, Module>, watched: &str) -> HighPulses {
+  let network = subnetwork(modules, watched);
+  let watch_list = HashSet::from([watched.to_string()]);
+  let press = |network: &HashMap<String, Module>| {
+    let mut next = network.clone();
+    push_button(&mut next, &watch_list);
+    next
+  };
+  let cycle = cycle::brent(network.clone(), press);
+
+  let mut network = network;
+  let mut high_pulses = HighPulses {
+    early: Vec::new(),
+    congruences: Vec::new(),
+    from: cycle.start as i128 + 1,
+  };
+  for presses in 1..=cycle.start + cycle.period {
+    let (_, _, pulses) = push_button(&mut network, &watch_list);
+    if !pulses.contains_key(watched) {
+      continue;
+    }
+    if presses > cycle.start {
+      high_pulses
+        .congruences
+        .push((presses as i128, cycle.period as i128));
+    } else {
+      high_pulses.early.push(presses as i128);
+    }
+  }
+  high_pulses
+}
+
+/// The fewest presses after which every module feeding the one in front of `rx` has sent a high
+/// pulse during the same press, so `rx` receives a low one, or `None` if nothing leads to `rx`.
+fn solve_part_two(modules: &HashMap<String, Module>) -> Option<u128> {
+  let rx_input = modules
+    .iter()
+    .find(|(_, module)| module.destinations.contains(&"rx".to_string()))
+    .map(|(name, _)| name.clone())?;
+
+  let watched: Vec<HighPulses> = modules
+    .iter()
+    .filter(|(_, module)| module.destinations.contains(&rx_input))
+    .map(|(name, _)| high_pulse_presses(modules, name))
+    .collect();
+
+  // Until every counter is in its cycle, try the presses one at a time.
+  let earliest = watched.iter().map(|pulses| pulses.from).max().unwrap_or(1);
+  if let Some(presses) = (1..earliest).find(|&press| watched.iter().all(|p| p.contains(press))) {
+    return Some(presses as u128);
+  }
+
+  // Combine the modules' congruences one at a time, keeping every consistent combination.
+  let mut solutions = vec![(0, 1)];
+  for pulses in &watched {
+    solutions = solutions
+      .iter()
+      .flat_map(|&solution| {
+        pulses
+          .congruences
+          .iter()
+          .filter_map(move |&congruence| math::crt(&[solution, congruence]))
+      })
+      .collect();
+  }
+
+  solutions
+    .into_iter()
+    .map(|(residue, modulus)| {
+      let behind = (earliest - residue).max(0);
+      residue + (behind + modulus - 1) / modulus * modulus
+    })
+    .min()
+    .map(|presses| presses as u128)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+  use crate::{differential, generate};
+  use rand::Rng;
+
+  /// Presses the button until `rx` receives a low pulse, giving up after `limit` presses.
+  fn brute_force_presses(modules: &HashMap<String, Module>, limit: u128) -> Option<u128> {
+    let mut modules = modules.clone();
+    for presses in 1..=limit {
+      let mut queue = VecDeque::from([("button".to_string(), "broadcaster".to_string(), false)]);
+      while let Some((from, to, high)) = queue.pop_front() {
+        if to == "rx" && !high {
+          return Some(presses);
+        }
+        let Some(module) = modules.get_mut(&to) else {
+          continue;
+        };
+        let output = match &mut module.module_type {
+          ModuleType::FlipFlop(_) if high => continue,
+          ModuleType::FlipFlop(on) => {
+            *on = !*on;
+            *on
+          }
+          ModuleType::Conjunction(memory) => {
+            memory.insert(from, high);
+            !memory.values().all(|&input| input)
+          }
+          ModuleType::Broadcaster => high,
+        };
+        for destination in &module.destinations {
+          queue.push_back((to.clone(), destination.clone(), output));
+        }
+      }
+    }
+    None
+  }
+
+  #[test]
+  fn test_counters_match_brute_force() {
+    let modules = |numbers: &Vec<u32>| parse_input(&generate::pulse_counters(numbers)).unwrap();
+    differential::check(
+      200,
+      |rng| {
+        (0..rng.random_range(1..=3))
+          .map(|_| rng.random_range(0..8) * 2 + 1)
+          .collect::<Vec<u32>>()
+      },
+      |numbers| {
+        let mut smaller: Vec<Vec<u32>> = differential::without_each(numbers)
+          .into_iter()
+          .filter(|numbers| !numbers.is_empty())
+          .collect();
+        for (i, &number) in numbers.iter().enumerate() {
+          if number > 1 {
+            let mut lower = numbers.clone();
+            lower[i] -= 2;
+            smaller.push(lower);
+          }
+        }
+        smaller
+      },
+      |numbers| solve_part_two(&modules(numbers)),
+      |numbers| brute_force_presses(&modules(numbers), 10_000),
+    );
+  }
+
+  #[test]
+  fn test_part1_full() {
+    assert_eq!(
+      solve_file::<Day20>("input/d20_full.txt", Part::One),
+      712543680
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day20>("input/d20_full.txt", Part::Two),
+      238920142622879
+    );
+  }
+}
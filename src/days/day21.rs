@@ -0,0 +1,174 @@
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::{Answer, ParseError, Solution, parse, search};
+
+pub struct Day21 {
+  garden: Garden,
+  part1_steps: i64,
+  part2_steps: i64,
+}
+
+impl Solution for Day21 {
+  const DAY: u8 = 21;
+  const TITLE: &'static str = "Step Counter";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day21 {
+      garden: Garden::from_input(input)?,
+      part1_steps: 64,
+      part2_steps: 26501365,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    self.garden.count_reachable_plots(self.part1_steps).into()
+  }
+
+  fn part2(&self) -> Answer {
+    self.garden.extrapolate_plots(self.part2_steps).into()
+  }
+
+  /// `steps` sets the step count of both parts.
+  fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+    match name {
+      "steps" => {
+        let steps = value
+          .parse()
+          .ok()
+          .filter(|&steps| steps >= 0)
+          .ok_or_else(|| format!("Invalid step count: {}", value))?;
+        self.part1_steps = steps;
+        self.part2_steps = steps;
+        Ok(())
+      }
+      _ => Err(format!("Day 21 has no parameter {}", name)),
+    }
+  }
+}
+
+/// The most steps [`Garden::extrapolate_plots`] searches, the most the puzzle's examples take.
+const MAX_SEARCH_STEPS: i64 = 5000;
+
+struct Garden {
+  grid: Grid<char>,
+  start: Point2,
+  width: i64,
+}
+
+impl Garden {
+  fn from_input(input: &str) -> Result<Self, ParseError> {
+    let grid = parse::grid(parse::lines(input), "tile", |c| {
+      matches!(c, '.' | '#' | 'S').then_some(c)
+    })?;
+    let width = grid.width() as i64;
+    let start = grid
+      .find(&'S')
+      .map(Point2::from)
+      .ok_or_else(|| ParseError::new("no start tile"))?;
+
+    Ok(Garden { grid, start, width })
+  }
+
+  /// Whether `point` is a garden plot of the map repeated infinitely in every direction.
+  fn is_garden_plot(&self, point: &Point2) -> bool {
+    *self.grid.get_wrapped(point.x, point.y) != '#'
+  }
+
+  fn count_reachable_plots(&self, steps: i64) -> i64 {
+    let distances = search::bfs_distances_within(self.start, steps as usize, |point| {
+      point
+        .neighbors()
+        .into_iter()
+        .filter(|neighbor| self.is_garden_plot(neighbor))
+    });
+
+    // A plot reached in fewer steps can be revisited by stepping back and forth, as long as the
+    // remaining steps are even.
+    distances
+      .values()
+      .filter(|&&distance| distance as i64 % 2 == steps % 2)
+      .count() as i64
+  }
+
+  /// [`Self::count_reachable_plots`] for step counts too large to search, on a square map whose
+  /// start has clear paths to the edges, as the puzzle's has.
+  ///
+  /// After half the width and then every further width, the reachable area has spread exactly one
+  /// more map in each direction, so the counts at those steps grow quadratically in the number of
+  /// maps. The quadratic is fitted to the first three. Other step counts are searched directly,
+  /// up to [`MAX_SEARCH_STEPS`]; beyond that there is no answer.
+  fn extrapolate_plots(&self, steps: i64) -> Option<i64> {
+    let half_width = self.width / 2;
+    if steps < half_width || (steps - half_width) % self.width != 0 {
+      return (steps <= MAX_SEARCH_STEPS).then(|| self.count_reachable_plots(steps));
+    }
+
+    let [first, second, third] =
+      [0, 1, 2].map(|maps| self.count_reachable_plots(half_width + self.width * maps));
+    let maps = (steps - half_width) / self.width;
+    crate::debug!(
+      "extrapolating {} maps from {}, {} and {} plots",
+      maps,
+      first,
+      second,
+      third
+    );
+    Some(first + maps * (second - first) + maps * (maps - 1) / 2 * (third - 2 * second + first))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+  use crate::{differential, generate};
+  use rand::Rng;
+
+  /// A map like the puzzle's, from [`generate::garden`], and a number of maps to spread over.
+  type Walk = (Vec<String>, i64);
+
+  fn generate_walk(rng: &mut impl Rng) -> Walk {
+    let half_width = rng.random_range(1..=5);
+    (generate::garden(rng, half_width), rng.random_range(0..=6))
+  }
+
+  fn shrink_walk((rows, maps): &Walk) -> Vec<Walk> {
+    let mut smaller: Vec<Walk> = (0..*maps).map(|fewer| (rows.clone(), fewer)).collect();
+    for (y, row) in rows.iter().enumerate() {
+      for (x, _) in row.match_indices('#') {
+        let mut rows = rows.clone();
+        rows[y].replace_range(x..=x, ".");
+        smaller.push((rows, *maps));
+      }
+    }
+    smaller
+  }
+
+  #[test]
+  fn test_extrapolation_matches_search() {
+    let garden = |rows: &[String]| Garden::from_input(&rows.join("\n")).unwrap();
+    differential::check(
+      100,
+      generate_walk,
+      shrink_walk,
+      |(rows, maps)| {
+        let garden = garden(rows);
+        garden
+          .extrapolate_plots(garden.width / 2 + garden.width * maps)
+          .unwrap()
+      },
+      |(rows, maps)| {
+        let garden = garden(rows);
+        garden.count_reachable_plots(garden.width / 2 + garden.width * maps)
+      },
+    );
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day21>("input/d21_full.txt", Part::Two),
+      608152828731262
+    );
+  }
+}
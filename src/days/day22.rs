@@ -0,0 +1,241 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::geometry::Point3;
+use crate::parse::{self, Line};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day22 {
+  bricks: Vec<Brick>,
+  supports: HashMap<usize, HashSet<usize>>,
+  supported_by: HashMap<usize, HashSet<usize>>,
+}
+
+impl Solution for Day22 {
+  const DAY: u8 = 22;
+  const TITLE: &'static str = "Sand Slabs";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let mut bricks: Vec<Brick> = parse::lines(input)
+      .map(Brick::from_line)
+      .collect::<Result<_, _>>()?;
+    simulate_falling(&mut bricks);
+    let (supports, supported_by) = build_support_graph(&bricks);
+    Ok(Day22 {
+      bricks,
+      supports,
+      supported_by,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    let (supports, supported_by) = (&self.supports, &self.supported_by);
+    let safe = (0..self.bricks.len())
+      .filter(|i| {
+        let is_safe = supports.get(i).is_none_or(|supported_bricks| {
+          supported_bricks.iter().all(|&j| {
+            supported_by
+              .get(&j)
+              .is_some_and(|supporters| supporters.len() > 1)
+          })
+        });
+        crate::trace!("brick {} is safe to disintegrate: {}", i, is_safe);
+        is_safe
+      })
+      .count();
+    crate::debug!("{} of {} bricks are safe", safe, self.bricks.len());
+    safe.into()
+  }
+
+  fn part2(&self) -> Answer {
+    (0..self.bricks.len())
+      .map(|i| {
+        let falling = count_falling_bricks(i, &self.supports, &self.supported_by);
+        crate::trace!("disintegrating brick {} makes {} fall", i, falling);
+        falling
+      })
+      .sum::<usize>()
+      .into()
+  }
+}
+
+/// The largest coordinate of a cube. Bricks lie above the ground at `z = 0` and, in the puzzle,
+/// within a few hundred cubes of the origin.
+const MAX_COORDINATE: i64 = 10_000;
+
+/// A brick spanning the cuboid between two corners, kept with `start` the lower corner on every
+/// axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Brick {
+  start: Point3,
+  end: Point3,
+}
+
+impl Brick {
+  fn from_line(line: Line) -> Result<Self, ParseError> {
+    let (start, end) = line
+      .text
+      .split_once('~')
+      .ok_or_else(|| line.expected("x,y,z~x,y,z"))?;
+    let (start, end) = (Point3::parse(line, start)?, Point3::parse(line, end)?);
+    let in_range = |point: Point3| {
+      [point.x, point.y]
+        .iter()
+        .all(|c| (0..=MAX_COORDINATE).contains(c))
+        && (1..=MAX_COORDINATE).contains(&point.z)
+    };
+    if !in_range(start) || !in_range(end) {
+      return Err(line.error(line.text, "coordinates out of range in"));
+    }
+    let axes = [start.x != end.x, start.y != end.y, start.z != end.z];
+    if axes.iter().filter(|&&differs| differs).count() > 1 {
+      return Err(line.error(line.text, "expected a straight line of cubes, got"));
+    }
+    Ok(Self {
+      start: start.min(end),
+      end: start.max(end),
+    })
+  }
+
+  fn lowest_z(&self) -> i64 {
+    self.start.z
+  }
+
+  fn highest_z(&self) -> i64 {
+    self.end.z
+  }
+
+  fn fall_to(&mut self, new_z: i64) {
+    let fall = Point3::new(0, 0, self.lowest_z() - new_z);
+    self.start = self.start - fall;
+    self.end = self.end - fall;
+  }
+
+  /// The `(x, y)` columns the brick occupies.
+  fn footprint(&self) -> impl Iterator<Item = (i64, i64)> + use<> {
+    let (start, end) = (self.start, self.end);
+    (start.x..=end.x).flat_map(move |x| (start.y..=end.y).map(move |y| (x, y)))
+  }
+
+  /// Whether the two bricks overlap when seen from above.
+  fn overlaps(&self, other: &Brick) -> bool {
+    self.start.x <= other.end.x
+      && other.start.x <= self.end.x
+      && self.start.y <= other.end.y
+      && other.start.y <= self.end.y
+  }
+}
+
+fn simulate_falling(bricks: &mut [Brick]) {
+  let mut height_map: HashMap<(i64, i64), i64> = HashMap::new();
+
+  bricks.sort_by_key(|b| b.lowest_z());
+
+  for brick in bricks.iter_mut() {
+    let max_height = brick
+      .footprint()
+      .map(|column| height_map.get(&column).copied().unwrap_or(0))
+      .max()
+      .unwrap_or(0);
+
+    brick.fall_to(max_height + 1);
+
+    for column in brick.footprint() {
+      height_map.insert(column, brick.highest_z());
+    }
+  }
+}
+
+fn build_support_graph(
+  bricks: &[Brick],
+) -> (
+  HashMap<usize, HashSet<usize>>,
+  HashMap<usize, HashSet<usize>>,
+) {
+  let mut supports = HashMap::new();
+  let mut supported_by = HashMap::new();
+
+  for (i, upper) in bricks.iter().enumerate() {
+    for (j, lower) in bricks.iter().enumerate() {
+      if i != j && upper.lowest_z() == lower.highest_z() + 1 && upper.overlaps(lower) {
+        supports.entry(j).or_insert_with(HashSet::new).insert(i);
+        supported_by.entry(i).or_insert_with(HashSet::new).insert(j);
+      }
+    }
+  }
+
+  (supports, supported_by)
+}
+
+fn count_falling_bricks(
+  i: usize,
+  supports: &HashMap<usize, HashSet<usize>>,
+  supported_by: &HashMap<usize, HashSet<usize>>,
+) -> usize {
+  let mut falling = HashSet::new();
+  let mut queue = VecDeque::new();
+  queue.push_back(i);
+  falling.insert(i);
+
+  while let Some(brick) = queue.pop_front() {
+    if let Some(supported_bricks) = supports.get(&brick) {
+      for &supported in supported_bricks {
+        if supported_by[&supported].is_subset(&falling) {
+          falling.insert(supported);
+          queue.push_back(supported);
+        }
+      }
+    }
+  }
+
+  falling.len() - 1 // Subtract 1 to exclude the initially disintegrated brick
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  const TEST_INPUT: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(Day22::parse(TEST_INPUT).unwrap().solve(Part::One), 5);
+  }
+
+  #[test]
+  fn test_support_graph() {
+    let mut bricks: Vec<Brick> = parse::lines(TEST_INPUT)
+      .map(Brick::from_line)
+      .collect::<Result<_, _>>()
+      .unwrap();
+    simulate_falling(&mut bricks);
+    let (supports, supported_by) = build_support_graph(&bricks);
+    // A holds up B and C, which both hold up D and E.
+    assert_eq!(supports[&0], HashSet::from([1, 2]));
+    assert_eq!(supported_by[&3], HashSet::from([1, 2]));
+    assert_eq!(supported_by[&4], HashSet::from([1, 2]));
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(Day22::parse(TEST_INPUT).unwrap().solve(Part::Two), 7);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(solve_file::<Day22>("input/d22_full.txt", Part::Two), 74287);
+  }
+
+  #[test]
+  fn test_parse_rejects_malformed_bricks() {
+    let error = Day22::parse("1,0,1~1,2,1\n0,0,2~2,0\n").err().unwrap();
+    let location = error.location.unwrap();
+    assert_eq!((location.line, location.column), (2, 7));
+  }
+}
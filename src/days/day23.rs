@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::geometry::Direction;
+use crate::grid::{Coord, Grid};
+use crate::{Answer, ParseError, Solution, budget, parse, search};
+
+pub struct Day23 {
+  map: Map,
+}
+
+impl Solution for Day23 {
+  const DAY: u8 = 23;
+  const TITLE: &'static str = "A Long Walk";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day23 {
+      map: Map::new(input)?,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    longest_hike(&self.map, false).into()
+  }
+
+  fn part2(&self) -> Answer {
+    longest_hike(&self.map, true).into()
+  }
+}
+
+struct Map {
+  tiles: Grid<char>,
+  start: Coord,
+  end: Coord,
+}
+
+impl Map {
+  /// The hike starts in the gap in the top row at column 1 and ends in the gap in the bottom row
+  /// one column from the right.
+  fn new(input: &str) -> Result<Self, ParseError> {
+    let tiles = parse::grid(parse::lines(input), "tile", |c| {
+      matches!(c, '#' | '.' | '^' | '>' | 'v' | '<').then_some(c)
+    })?;
+    let (width, height) = (tiles.width(), tiles.height());
+    let start = Coord::new(1, 0);
+    let end = Coord::new(width.saturating_sub(2), height - 1);
+    if height < 2 || width < 3 || tiles[start] != '.' || tiles[end] != '.' {
+      return Err(ParseError::new(
+        "expected the start at column 2 of the top row and the end one column from the right of the bottom row",
+      ));
+    }
+    Ok(Map { tiles, start, end })
+  }
+
+  fn get_neighbors(&self, pos: Coord, ignore_slopes: bool) -> impl Iterator<Item = Coord> + '_ {
+    self.tiles.neighbors4(pos).filter(move |&next| {
+      self.tiles[next] != '#' && (ignore_slopes || self.is_valid_move(pos, next))
+    })
+  }
+
+  /// Slopes can only be left downhill, in the direction of their arrow.
+  fn is_valid_move(&self, from: Coord, to: Coord) -> bool {
+    match Direction::from_arrow(self.tiles[from]) {
+      Some(downhill) => self.tiles.neighbor(from, downhill) == Some(to),
+      None => true,
+    }
+  }
+}
+
+#[derive(Clone, Debug)]
+struct Edge {
+  to: Coord,
+  distance: usize,
+}
+
+fn compress_graph(map: &Map, ignore_slopes: bool) -> HashMap<Coord, Vec<Edge>> {
+  let mut graph = HashMap::new();
+  let mut junctions = HashSet::new();
+  junctions.insert(map.start);
+  junctions.insert(map.end);
+
+  // Find all junctions
+  for (pos, &tile) in map.tiles.iter() {
+    if tile != '#' && map.get_neighbors(pos, ignore_slopes).count() > 2 {
+      junctions.insert(pos);
+    }
+  }
+
+  // Connect junctions
+  for &start in &junctions {
+    let distances = search::bfs_distances(start, |&pos| {
+      let stop = pos != start && junctions.contains(&pos);
+      map.get_neighbors(pos, ignore_slopes).filter(move |_| !stop)
+    });
+    let edges = distances
+      .into_iter()
+      .filter(|(pos, _)| *pos != start && junctions.contains(pos))
+      .map(|(to, distance)| Edge { to, distance });
+    graph.insert(start, edges.collect());
+  }
+
+  crate::debug!(
+    "compressed the map to {} junctions and {} edges",
+    graph.len(),
+    graph.values().map(Vec::len).sum::<usize>()
+  );
+  graph
+}
+
+fn dfs(
+  graph: &HashMap<Coord, Vec<Edge>>,
+  pos: Coord,
+  end: Coord,
+  visited: &mut HashSet<Coord>,
+  current_distance: usize,
+) -> Option<usize> {
+  if pos == end {
+    return Some(current_distance);
+  }
+
+  // The number of paths grows exponentially with the junctions, so open maps can take forever.
+  budget::tick("junctions visited");
+  visited.insert(pos);
+  let mut max_distance = None;
+
+  if let Some(edges) = graph.get(&pos) {
+    for edge in edges {
+      if !visited.contains(&edge.to)
+        && let Some(distance) = dfs(
+          graph,
+          edge.to,
+          end,
+          visited,
+          current_distance + edge.distance,
+        )
+      {
+        max_distance = max_distance.max(Some(distance));
+      }
+    }
+  }
+
+  visited.remove(&pos);
+  max_distance
+}
+
+fn longest_hike(map: &Map, ignore_slopes: bool) -> usize {
+  let graph = compress_graph(map, ignore_slopes);
+  let mut visited = HashSet::new();
+  dfs(&graph, map.start, map.end, &mut visited, 0).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Part, solve_file};
+
+  const INPUT: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+  #[test]
+  fn test_part1_simple() {
+    assert_eq!(Day23::parse(INPUT).unwrap().solve(Part::One), 94);
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    assert_eq!(Day23::parse(INPUT).unwrap().solve(Part::Two), 154);
+  }
+
+  #[test]
+  fn test_part2_full() {
+    // runs a bit long (~48s)
+    assert_eq!(solve_file::<Day23>("input/d23_full.txt", Part::Two), 6802);
+  }
+}
@@ -0,0 +1,341 @@
+use rand::prelude::*;
+use std::ops::RangeInclusive;
+
+use crate::budget;
+use crate::geometry::Point3;
+use crate::parse::{self, Line};
+use crate::random;
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day24 {
+  hailstones: Vec<Hailstone>,
+  /// Bounds of the square in which part 1 counts crossings, on both the X and Y axes.
+  test_area: RangeInclusive<f64>,
+}
+
+impl Solution for Day24 {
+  const DAY: u8 = 24;
+  const TITLE: &'static str = "Never Tell Me The Odds";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    Ok(Day24 {
+      hailstones: parse_input(input)?,
+      test_area: 200000000000000.0..=400000000000000.0,
+    })
+  }
+
+  fn part1(&self) -> Answer {
+    solve_part1(&self.hailstones, &self.test_area).into()
+  }
+
+  fn part2(&self) -> Answer {
+    solve_part2(&self.hailstones, &mut random::rng()).into()
+  }
+
+  /// `test_area` takes the bounds as `min..max`, e.g. `7..27` for the example.
+  fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+    match name {
+      "test_area" => {
+        let (min, max) = value
+          .split_once("..")
+          .and_then(|(min, max)| Some((min.trim().parse().ok()?, max.trim().parse().ok()?)))
+          .filter(|(min, max)| min <= max)
+          .ok_or_else(|| format!("Invalid test area: {} (expected min..max)", value))?;
+        self.test_area = min..=max;
+        Ok(())
+      }
+      _ => Err(format!("Day 24 has no parameter {}", name)),
+    }
+  }
+}
+
+/// The largest coordinate of a position, up to which every integer is exact as an `f64`. The
+/// puzzle's are below 10^15.
+const MAX_POSITION: u64 = 1 << 53;
+/// The largest component of a velocity. The puzzle's are in the hundreds.
+const MAX_SPEED: u64 = 1 << 32;
+
+#[derive(Debug, Clone)]
+struct Hailstone {
+  position: Point3,
+  velocity: Point3,
+  slope: Option<f64>,
+}
+
+impl Hailstone {
+  fn from_line(line: Line) -> Result<Self, ParseError> {
+    let parts: Vec<&str> = line.text.split('@').collect();
+    if parts.len() != 2 {
+      return Err(line.expected("px, py, pz @ vx, vy, vz"));
+    }
+
+    let position = Point3::parse(line, parts[0])?;
+    let velocity = Point3::parse(line, parts[1])?;
+    let within = |point: Point3, max: u64| {
+      [point.x, point.y, point.z]
+        .iter()
+        .all(|c| c.unsigned_abs() <= max)
+    };
+    if !within(position, MAX_POSITION) || !within(velocity, MAX_SPEED) {
+      return Err(line.error(line.text, "coordinates out of range in"));
+    }
+
+    let slope = if velocity.x == 0 {
+      None
+    } else {
+      Some(velocity.y as f64 / velocity.x as f64)
+    };
+
+    Ok(Hailstone {
+      position,
+      velocity,
+      slope,
+    })
+  }
+
+  fn with_velocity_delta(&self, vx: i64, vy: i64) -> Self {
+    let new_velocity = self.velocity + Point3::new(vx, vy, 0);
+
+    let new_slope = if new_velocity.x == 0 {
+      None
+    } else {
+      Some(new_velocity.y as f64 / new_velocity.x as f64)
+    };
+
+    Hailstone {
+      position: self.position,
+      velocity: new_velocity,
+      slope: new_slope,
+    }
+  }
+
+  fn predict_z(&self, time: f64, delta_vz: i64) -> f64 {
+    self.position.z as f64 + time * (self.velocity.z + delta_vz) as f64
+  }
+
+  fn intersection_with(&self, other: &Hailstone) -> Option<Intersection> {
+    match (self.slope, other.slope) {
+      (Some(slope), Some(other_slope)) if slope != other_slope => {
+        let c = self.position.y as f64 - slope * self.position.x as f64;
+        let other_c = other.position.y as f64 - other_slope * other.position.x as f64;
+
+        let x = (other_c - c) / (slope - other_slope);
+        let t1 = (x - self.position.x as f64) / self.velocity.x as f64;
+        let t2 = (x - other.position.x as f64) / other.velocity.x as f64;
+
+        if t1 < 0.0 || t2 < 0.0 {
+          None
+        } else {
+          let y = slope * (x - self.position.x as f64) + self.position.y as f64;
+          Some(Intersection { x, y, time: t1 })
+        }
+      }
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Intersection {
+  x: f64,
+  y: f64,
+  time: f64,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+  parse::lines(input).map(Hailstone::from_line).collect()
+}
+
+fn solve_part1(hailstones: &[Hailstone], range: &RangeInclusive<f64>) -> usize {
+  hailstones
+    .iter()
+    .enumerate()
+    .flat_map(|(i, h1)| {
+      hailstones[i + 1..].iter().filter_map(move |h2| {
+        h1.intersection_with(h2)
+          .filter(|intersection| range.contains(&intersection.x) && range.contains(&intersection.y))
+      })
+    })
+    .count()
+}
+
+/// Whether two coordinates computed in floating point are the same point, allowing for rounding
+/// errors relative to their size.
+fn nearly_equal(a: f64, b: f64) -> bool {
+  (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0)
+}
+
+/// Whether a rock thrown from `position` with `velocity` hits every hailstone, checked exactly so
+/// that a velocity which only nearly lines up with the sampled hailstones is rejected.
+fn hits_all(position: Point3, velocity: Point3, hailstones: &[Hailstone]) -> bool {
+  hailstones.iter().all(|hailstone| {
+    // The rock hits the hailstone at time t if `offset == t * closing` for some t >= 0.
+    let offset = [
+      hailstone.position.x as i128 - position.x as i128,
+      hailstone.position.y as i128 - position.y as i128,
+      hailstone.position.z as i128 - position.z as i128,
+    ];
+    let closing = [
+      velocity.x as i128 - hailstone.velocity.x as i128,
+      velocity.y as i128 - hailstone.velocity.y as i128,
+      velocity.z as i128 - hailstone.velocity.z as i128,
+    ];
+    let product = |a: usize, b: usize| offset[a].checked_mul(closing[b]);
+    let parallel = [(0, 1), (1, 2), (2, 0)]
+      .iter()
+      .all(|&(a, b)| product(a, b).is_some() && product(a, b) == product(b, a));
+    let dot = (0..3).try_fold(0i128, |sum, i| sum.checked_add(product(i, i)?));
+    parallel && dot.is_some_and(|dot| dot > 0 || offset == [0; 3])
+  })
+}
+
+/// Sum of the coordinates of the rock's starting position, or `None` if there are too few
+/// hailstones to pin it down.
+///
+/// Samples of four hailstones are tried until one gives a rock that hits every hailstone, so this
+/// only returns on inputs that have such a rock, or by running out of [`budget`].
+fn solve_part2(hailstones: &[Hailstone], rng: &mut impl Rng) -> Option<i64> {
+  // A copy of a hailstone adds nothing, but a sample holding copies lines up for every velocity,
+  // which would check each one in full between two ticks of the budget.
+  let mut distinct: Vec<&Hailstone> = Vec::new();
+  for hailstone in hailstones {
+    let same = |other: &&Hailstone| {
+      (other.position, other.velocity) == (hailstone.position, hailstone.velocity)
+    };
+    if !distinct.iter().any(same) {
+      distinct.push(hailstone);
+    }
+  }
+  if distinct.len() < 4 {
+    return None;
+  }
+  let range = -500..=500;
+
+  let mut samples = 0;
+  loop {
+    samples += 1;
+    let hail: Vec<Hailstone> = distinct
+      .choose_multiple(rng, 4)
+      .map(|&hailstone| hailstone.clone())
+      .collect();
+    for delta_x in range.clone() {
+      budget::tick("velocity rows");
+      for delta_y in range.clone() {
+        let hail0 = hail[0].with_velocity_delta(delta_x, delta_y);
+        let intercepts: Vec<_> = hail
+          .iter()
+          .skip(1)
+          .filter_map(|h| {
+            h.with_velocity_delta(delta_x, delta_y)
+              .intersection_with(&hail0)
+          })
+          .collect();
+
+        if intercepts.len() == 3
+          && intercepts
+            .iter()
+            .all(|i| nearly_equal(i.x, intercepts[0].x))
+          && intercepts
+            .iter()
+            .all(|i| nearly_equal(i.y, intercepts[0].y))
+        {
+          for delta_z in range.clone() {
+            let z1 = hail[1].predict_z(intercepts[0].time, delta_z);
+            let z2 = hail[2].predict_z(intercepts[1].time, delta_z);
+            let z3 = hail[3].predict_z(intercepts[2].time, delta_z);
+
+            if nearly_equal(z1, z2) && nearly_equal(z2, z3) {
+              let position = Point3::new(
+                intercepts[0].x.round() as i64,
+                intercepts[0].y.round() as i64,
+                z1.round() as i64,
+              );
+              let velocity = Point3::new(-delta_x, -delta_y, -delta_z);
+              if hits_all(position, velocity, hailstones) {
+                crate::debug!(
+                  "rock at {:?} with velocity {:?}, found in sample {}",
+                  position,
+                  velocity,
+                  samples
+                );
+                return position.x.checked_add(position.y)?.checked_add(position.z);
+              }
+              crate::trace!("velocity {:?} misses some hailstones", velocity);
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::budget::Budget;
+  use crate::{Part, solve_file};
+
+  #[test]
+  fn test_part1_simple() {
+    let input = "19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4\n12, 31, 28 @ -1, -2, -1\n20, 19, 15 @  1, -5, -3";
+    let range = 7.0..=27.0;
+    let hailstones = parse_input(input).unwrap();
+    assert_eq!(solve_part1(&hailstones, &range).to_string(), "2");
+  }
+
+  #[test]
+  fn test_part2_simple() {
+    let input = "19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4\n12, 31, 28 @ -1, -2, -1\n20, 19, 15 @  1, -5, -3";
+    assert_eq!(Day24::parse(input).unwrap().solve(Part::Two), 47);
+  }
+
+  #[test]
+  fn test_part2_gives_up_without_a_rock() {
+    // The example with the last hailstone one unit higher, so that no rock hits all of them.
+    let input = "19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4\n12, 31, 28 @ -1, -2, -1\n20, 19, 16 @  1, -5, -3";
+    let hailstones = parse_input(input).unwrap();
+    let budget = Budget {
+      time: None,
+      steps: Some(5000),
+    };
+    let gave_up = budget::run(budget, || {
+      solve_part2(&hailstones, &mut StdRng::seed_from_u64(2023))
+    })
+    .unwrap_err();
+    assert_eq!((gave_up.steps, gave_up.unit), (5000, "velocity rows"));
+  }
+
+  #[test]
+  fn test_part2_ignores_copies_of_hailstones() {
+    // Three copies would match every velocity, each taking a full row of checks to reject.
+    let input = "19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2\n12, 31, 28 @ -1, -2, -1\n\
+                 20, 25, 34 @ -2, -2, -4\n12, 31, 28 @ -1, -2, -1\n12, 31, 28 @ -1, -2, -1\n\
+                 20, 19, 15 @  1, -5, -3";
+    let hailstones = parse_input(input).unwrap();
+    for seed in 0..20 {
+      assert_eq!(
+        solve_part2(&hailstones, &mut StdRng::seed_from_u64(seed)),
+        Some(47)
+      );
+    }
+    assert_eq!(
+      solve_part2(&hailstones[4..], &mut StdRng::seed_from_u64(0)),
+      None
+    );
+  }
+
+  #[test]
+  fn test_parse_rejects_huge_numbers() {
+    assert!(parse_input("19, 13, 30 @ -2, 1, -2").is_ok());
+    assert!(parse_input("19, 13, 30 @ -2, 1, 9223372036854775807").is_err());
+    assert!(parse_input("9007199254740993, 13, 30 @ -2, 1, -2").is_err());
+  }
+
+  #[test]
+  fn test_part2_full() {
+    assert_eq!(
+      solve_file::<Day24>("input/d24_full.txt", Part::Two),
+      1025127405449117
+    );
+  }
+}
@@ -0,0 +1,217 @@
+use rand::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::budget;
+use crate::parse::{self, Line};
+use crate::random;
+use crate::{Answer, ParseError, Solution};
+
+/// Ordered, so that the same seed picks the same components on every run.
+type Graph = BTreeMap<String, Vec<String>>;
+
+pub struct Day25 {
+  graph: Graph,
+}
+
+impl Solution for Day25 {
+  const DAY: u8 = 25;
+  const TITLE: &'static str = "Snowverload";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {
+    let mut graph = Graph::new();
+    for line in parse::lines(input) {
+      add_connections(&mut graph, line)?;
+    }
+    if graph.len() < 2 {
+      return Err(ParseError::new("expected at least two components"));
+    }
+    Ok(Day25 { graph })
+  }
+
+  fn part1(&self) -> Answer {
+    self.solve_part_1(&mut random::rng()).into()
+  }
+
+  /// Day 25 has no second puzzle: its star is awarded for finishing the other 49.
+  fn part2(&self) -> Answer {
+    Answer::None
+  }
+}
+
+impl Day25 {
+  /// Product of the sizes of the two groups left by cutting three wires, found with Karger's
+  /// random contractions. Only returns on inputs that have such a cut, or by running out of
+  /// [`budget`].
+  ///
+  /// # Panics
+  ///
+  /// If the product does not fit in a `u64`.
+  fn solve_part_1(&self, rng: &mut impl Rng) -> u64 {
+    let mut attempt = 0;
+    loop {
+      attempt += 1;
+      let mut graph = self.graph.clone();
+      let mut counts: HashMap<String, usize> = graph.keys().map(|k| (k.clone(), 1)).collect();
+
+      while graph.len() > 2 {
+        budget::tick("contractions");
+        // Get random node 'a'
+        let keys: Vec<_> = graph.keys().cloned().collect();
+        let node_a = keys.choose(rng).unwrap().clone();
+
+        // Get random neighbor 'b' of 'a'; a node without any means the graph is disconnected,
+        // so there is no cut of size 3 to find.
+        let neighbors = &graph[&node_a];
+        let Some(node_b) = neighbors.choose(rng).cloned() else {
+          break;
+        };
+
+        // Create new super-node
+        let new_node = format!("{}-{}", node_a, node_b);
+
+        // Update counts
+        let count_a = counts.remove(&node_a).unwrap_or(0);
+        let count_b = counts.remove(&node_b).unwrap_or(0);
+        counts.insert(new_node.clone(), count_a + count_b);
+
+        // Combine and merge nodes
+        self.combine_values(&mut graph, &node_a, &node_b, &new_node);
+        self.merge_nodes(&mut graph, &node_a, &new_node);
+        self.merge_nodes(&mut graph, &node_b, &new_node);
+      }
+
+      // Check if we found the minimum cut of size 3
+      let remaining_keys: Vec<_> = graph.keys().collect();
+      let &[node_a, node_b] = remaining_keys.as_slice() else {
+        continue;
+      };
+
+      crate::trace!("attempt {} cut {} wires", attempt, graph[node_a].len());
+      if graph[node_a].len() == 3 {
+        crate::debug!(
+          "cut into groups of {} and {} components after {} attempts",
+          counts[node_a],
+          counts[node_b],
+          attempt
+        );
+        return (counts[node_a] as u64)
+          .checked_mul(counts[node_b] as u64)
+          .expect("answer overflows u64");
+      }
+    }
+  }
+
+  fn combine_values(&self, graph: &mut Graph, node_a: &str, node_b: &str, new_node: &str) {
+    let edges_a: Vec<_> = graph[node_a]
+      .iter()
+      .filter(|&neighbor| neighbor != node_b)
+      .cloned()
+      .collect();
+
+    let edges_b: Vec<_> = graph[node_b]
+      .iter()
+      .filter(|&neighbor| neighbor != node_a)
+      .cloned()
+      .collect();
+
+    let mut combined_edges = edges_a;
+    combined_edges.extend(edges_b);
+
+    graph.insert(new_node.to_string(), combined_edges);
+  }
+
+  fn merge_nodes(&self, graph: &mut Graph, old_node: &str, new_node: &str) {
+    if let Some(neighbors) = graph.remove(old_node) {
+      for target in neighbors {
+        if let Some(target_neighbors) = graph.get_mut(&target) {
+          for neighbor in target_neighbors.iter_mut() {
+            if neighbor == old_node {
+              *neighbor = new_node.to_string();
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+fn add_connections(graph: &mut Graph, line: Line) -> Result<(), ParseError> {
+  let (source_name, connections) = line
+    .text
+    .split_once(':')
+    .ok_or_else(|| line.expected("<component>: <components>"))?;
+  let source_name = source_name.trim();
+  if source_name.is_empty() {
+    return Err(line.error(line.text, "missing component name in"));
+  }
+
+  // Ensure source exists in graph
+  graph.entry(source_name.to_string()).or_default();
+
+  for connection in connections.split_whitespace() {
+    if connection == source_name {
+      return Err(line.error(connection, "component connected to itself"));
+    }
+    // Add bidirectional edges
+    graph
+      .entry(source_name.to_string())
+      .or_default()
+      .push(connection.to_string());
+
+    graph
+      .entry(connection.to_string())
+      .or_default()
+      .push(source_name.to_string());
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::budget::Budget;
+
+  #[test]
+  fn test_parse_rejects_self_connections() {
+    assert!(Day25::parse("a: b a").is_err());
+  }
+
+  #[test]
+  fn test_gives_up_without_a_three_wire_cut() {
+    // Five components wired to each other: every cut has at least four wires.
+    let solver = Day25::parse("a: b c d e\nb: c d e\nc: d e\nd: e").unwrap();
+    let budget = Budget {
+      time: None,
+      steps: Some(1000),
+    };
+    let gave_up = budget::run(budget, || {
+      solver.solve_part_1(&mut StdRng::seed_from_u64(2023))
+    })
+    .unwrap_err();
+    assert_eq!((gave_up.steps, gave_up.unit), (1000, "contractions"));
+  }
+
+  #[test]
+  fn test_example() {
+    let input = [
+      "jqt: rhn xhk nvd",
+      "rsh: frs pzl lsr",
+      "xhk: hfx",
+      "cmg: qnr nvd lhk bvb",
+      "rhn: xhk bvb hfx",
+      "bvb: xhk hfx",
+      "pzl: lsr hfx nvd",
+      "qnr: nvd",
+      "ntq: jqt hfx bvb xhk",
+      "nvd: lhk",
+      "lsr: lhk",
+      "rzs: qnr cmg lsr rsh",
+      "frs: qnr lhk lsr",
+    ];
+
+    let solver = Day25::parse(&input.join("\n")).unwrap();
+    let result = solver.solve_part_1(&mut StdRng::seed_from_u64(2023));
+    assert_eq!(result, 54);
+  }
+}
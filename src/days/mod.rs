@@ -0,0 +1,76 @@
+// Index loops over 2-D grids read more clearly than zipped iterators in puzzle code.
+#![allow(clippy::needless_range_loop)]
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::Day;
+
+/// Every day of the calendar, in order.
+pub const ALL: [Day; 25] = [
+  Day::of::<day01::Day01>(),
+  Day::of::<day02::Day02>(),
+  Day::of::<day03::Day03>(),
+  Day::of::<day04::Day04>(),
+  Day::of::<day05::Day05>(),
+  Day::of::<day06::Day06>(),
+  Day::of::<day07::Day07>(),
+  Day::of::<day08::Day08>(),
+  Day::of::<day09::Day09>(),
+  Day::of::<day10::Day10>(),
+  Day::of::<day11::Day11>(),
+  Day::of::<day12::Day12>(),
+  Day::of::<day13::Day13>(),
+  Day::of::<day14::Day14>(),
+  Day::of::<day15::Day15>(),
+  Day::of::<day16::Day16>(),
+  Day::of::<day17::Day17>(),
+  Day::of::<day18::Day18>(),
+  Day::of::<day19::Day19>(),
+  Day::of::<day20::Day20>(),
+  Day::of::<day21::Day21>(),
+  Day::of::<day22::Day22>(),
+  Day::of::<day23::Day23>(),
+  Day::of::<day24::Day24>(),
+  Day::of::<day25::Day25>(),
+];
+
+/// Looks up a day by its number (1-25).
+pub fn get(number: u8) -> Option<&'static Day> {
+  ALL.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_registry_is_in_calendar_order() {
+    for (i, day) in ALL.iter().enumerate() {
+      assert_eq!(day.number as usize, i + 1);
+    }
+  }
+}
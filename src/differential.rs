@@ -0,0 +1,146 @@
+//! Differential testing: a fast solver against a slow, obviously correct reference.
+//!
+//! [`check`] runs both on many small random inputs. When they disagree it shrinks the input to
+//! a minimal one that still shows the difference and panics with it, so a failure reads as a
+//! small counterexample rather than a large random one.
+
+use std::fmt::Debug;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::verify::catch_panic;
+
+/// Seed of the input generators, fixed so that failures reproduce.
+const SEED: u64 = 2023;
+
+/// A minimized input on which the two solvers disagree. A panic counts as an output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Mismatch<I, O> {
+  pub input: I,
+  pub fast: Result<O, String>,
+  pub slow: Result<O, String>,
+}
+
+/// Runs `fast` and `slow` on `cases` inputs from `generate` and returns the first disagreement,
+/// minimized by repeatedly taking the first of `shrink`'s smaller variants that still disagrees.
+pub(crate) fn find_mismatch<I, O>(
+  cases: usize,
+  mut generate: impl FnMut(&mut StdRng) -> I,
+  shrink: impl Fn(&I) -> Vec<I>,
+  fast: impl Fn(&I) -> O,
+  slow: impl Fn(&I) -> O,
+) -> Option<Mismatch<I, O>>
+where
+  O: PartialEq,
+{
+  let mut rng = StdRng::seed_from_u64(SEED);
+  let disagreement = |input: I| {
+    let fast_output = catch_panic(|| fast(&input));
+    let slow_output = catch_panic(|| slow(&input));
+    (fast_output != slow_output).then_some(Mismatch {
+      input,
+      fast: fast_output,
+      slow: slow_output,
+    })
+  };
+
+  let mut mismatch = (0..cases).find_map(|_| disagreement(generate(&mut rng)))?;
+  while let Some(smaller) = shrink(&mismatch.input).into_iter().find_map(&disagreement) {
+    mismatch = smaller;
+  }
+  Some(mismatch)
+}
+
+/// [`find_mismatch`], panicking with the minimized input if there is one.
+pub(crate) fn check<I, O>(
+  cases: usize,
+  generate: impl FnMut(&mut StdRng) -> I,
+  shrink: impl Fn(&I) -> Vec<I>,
+  fast: impl Fn(&I) -> O,
+  slow: impl Fn(&I) -> O,
+) where
+  I: Debug,
+  O: PartialEq + Debug,
+{
+  if let Some(mismatch) = find_mismatch(cases, generate, shrink, fast, slow) {
+    panic!(
+      "fast and reference solvers disagree\ninput: {:#?}\nfast: {:?}\nreference: {:?}",
+      mismatch.input, mismatch.fast, mismatch.slow
+    );
+  }
+}
+
+/// Shrinks a list by dropping one element at a time, the most common way to simplify an input.
+pub(crate) fn without_each<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+  (0..items.len())
+    .map(|i| {
+      let mut smaller = items.to_vec();
+      smaller.remove(i);
+      smaller
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  #[test]
+  fn test_agreeing_solvers_pass() {
+    let sum = |numbers: &Vec<u32>| numbers.iter().sum::<u32>();
+    let generate = |rng: &mut StdRng| {
+      (0..rng.random_range(0..10))
+        .map(|_| rng.random_range(0..100))
+        .collect()
+    };
+    assert_eq!(
+      find_mismatch(
+        200,
+        generate,
+        |v| without_each(v),
+        sum,
+        |v| {
+          let mut total = 0;
+          for n in v {
+            total += n;
+          }
+          total
+        }
+      ),
+      None
+    );
+  }
+
+  #[test]
+  fn test_mismatches_are_minimized() {
+    // A "fast" sum that forgets everything past the third number, and panics on 13.
+    let buggy = |numbers: &Vec<u32>| {
+      assert!(!numbers.contains(&13), "unlucky");
+      numbers.iter().take(3).sum::<u32>()
+    };
+    let generate = |rng: &mut StdRng| {
+      (0..rng.random_range(5..10))
+        .map(|_| rng.random_range(1..100))
+        .collect()
+    };
+    let mismatch =
+      find_mismatch(10, generate, |v| without_each(v), buggy, |v| v.iter().sum()).unwrap();
+
+    // Four positive numbers is the smallest input whose fourth number is dropped.
+    assert_eq!(mismatch.input.len(), 4);
+    assert_ne!(mismatch.fast, mismatch.slow);
+
+    let mismatch = find_mismatch(
+      1,
+      |_| vec![1, 13, 2],
+      |v| without_each(v),
+      buggy,
+      |v| v.iter().sum(),
+    )
+    .unwrap();
+    assert_eq!(mismatch.input, vec![13]);
+    assert_eq!(mismatch.fast, Err("unlucky".to_string()));
+  }
+}
@@ -0,0 +1,434 @@
+//! Fuzzing of every day's parser and solver.
+//!
+//! [`target`] is the entry point: it feeds arbitrary bytes to a day as its input and solves
+//! both parts. Whatever the bytes, it must return, either with the answers or with a
+//! [`ParseError`]; a panic or a part that never finishes is a bug. Giving up within the
+//! [`Budget`] counts as finishing. [`fuzz`] drives it with
+//! random mutations of a corpus of inputs, by default the files under `fuzz/corpus/dNN/`, which
+//! start out as copies of the sample inputs.
+//!
+//! The mutations are blind rather than coverage-guided: they edit bytes, numbers and lines, and
+//! splice inputs together, which is enough to reach the checks of line-based parsers.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::budget::{self, Budget, GaveUp};
+use crate::parallel::{self, Failure};
+use crate::random;
+use crate::{Answer, Day, ParseError, Part, input};
+
+/// Directory of the checked-in corpus, one `dNN` subdirectory per day.
+pub const CORPUS_DIR: &str = "fuzz/corpus";
+/// Directory where [`save_artifact`] keeps the inputs that broke a day.
+pub const ARTIFACT_DIR: &str = "fuzz/artifacts";
+
+/// Numbers that make good replacements for the ones in an input: zero, signs and the edges of
+/// the integer types parsers tend to use.
+const INTERESTING_NUMBERS: [&str; 10] = [
+  "0",
+  "1",
+  "-1",
+  "2",
+  "255",
+  "65536",
+  "4294967296",
+  "-9223372036854775808",
+  "9223372036854775807",
+  "340282366920938463463374607431768211456",
+];
+
+/// Answers to both parts, each of which may have given up.
+pub type Answers = [Result<Answer, GaveUp>; 2];
+
+/// Feeds `data` to `day` as its input and solves both parts, each within `budget`.
+///
+/// The bytes are read the way an input file is, with invalid UTF-8 replaced and line endings
+/// normalized. Panics are not caught; see [`check`].
+pub fn target(day: Day, data: &[u8], budget: Budget) -> Result<Answers, ParseError> {
+  let text = input::normalize(&String::from_utf8_lossy(data));
+  let puzzle = day.parse(&text)?;
+  Ok(Part::BOTH.map(|part| budget::run(budget, || puzzle.solve(part))))
+}
+
+/// [`target`] on a thread of its own, so that a panic or a run longer than `options.timeout`
+/// comes back as a [`Failure`]. A run that times out keeps going in the background. The thread
+/// inherits the seed of the enclosing [`random::with_seed`], if any.
+pub fn check(
+  day: Day,
+  data: &[u8],
+  options: Options,
+) -> Result<Result<Answers, ParseError>, Failure> {
+  let schedule = parallel::Options {
+    jobs: 1,
+    timeout: Some(options.timeout),
+  };
+  let budget = options.budget();
+  let seed = random::seed();
+  parallel::map(vec![data.to_vec()], schedule, move |data| match seed {
+    Some(seed) => random::with_seed(seed, || target(day, &data, budget)),
+    None => target(day, &data, budget),
+  })
+  .pop()
+  .expect("one job gives one result")
+}
+
+/// How [`fuzz`] runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+  /// Mutated inputs to try.
+  pub runs: usize,
+  /// How long one input may take before the day counts as hung.
+  pub timeout: Duration,
+  /// Budget steps each part may take before giving up, besides a quarter of the timeout.
+  pub steps: Option<u64>,
+}
+
+impl Options {
+  /// The budget of each part, which leaves both parts time to give up before the timeout.
+  pub fn budget(&self) -> Budget {
+    Budget {
+      time: Some(self.timeout / 4),
+      steps: self.steps,
+    }
+  }
+}
+
+impl Default for Options {
+  fn default() -> Self {
+    Options {
+      runs: 10_000,
+      timeout: Duration::from_secs(5),
+      steps: None,
+    }
+  }
+}
+
+/// An input on which [`target`] panicked or hung.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crash {
+  pub input: Vec<u8>,
+  pub failure: Failure,
+}
+
+/// What a [`fuzz`] run found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+  /// Inputs tried, including the corpus itself.
+  pub runs: usize,
+  /// Inputs that parsed, as opposed to being rejected with an error.
+  pub parsed: usize,
+  /// The first input that broke the day, minimized if it panicked.
+  pub crash: Option<Crash>,
+}
+
+/// Runs the corpus and then `options.runs` mutations of it through [`target`], stopping at the
+/// first panic or hang.
+pub fn fuzz(day: Day, corpus: &[Vec<u8>], options: Options, rng: &mut impl Rng) -> Report {
+  let mut report = Report {
+    runs: 0,
+    parsed: 0,
+    crash: None,
+  };
+  let seeds = corpus.iter().cloned();
+  let mutations = (0..options.runs).map(|_| mutate(corpus, rng));
+  for input in seeds.chain(mutations) {
+    report.runs += 1;
+    match check(day, &input, options) {
+      Ok(Ok(_)) => report.parsed += 1,
+      Ok(Err(_)) => {}
+      Err(Failure::Panic(_)) => {
+        report.crash = Some(minimize(day, input, options));
+        break;
+      }
+      // Every attempt to shrink a hang would leave another thread spinning, so it is kept whole.
+      Err(Failure::Timeout) => {
+        report.crash = Some(Crash {
+          input,
+          failure: Failure::Timeout,
+        });
+        break;
+      }
+    }
+  }
+  report
+}
+
+/// Shrinks an input that makes `day` panic by dropping lines and then single bytes for as long
+/// as it still panics.
+fn minimize(day: Day, mut input: Vec<u8>, options: Options) -> Crash {
+  let panics = |input: &[u8]| match check(day, input, options) {
+    Err(Failure::Panic(message)) => Some(message),
+    _ => None,
+  };
+  let mut message = panics(&input).unwrap_or_else(|| "panicked only once".to_string());
+
+  loop {
+    let lines: Vec<&[u8]> = input.split_inclusive(|&b| b == b'\n').collect();
+    let without_line = (0..lines.len()).map(|i| {
+      let mut smaller = lines.clone();
+      smaller.remove(i);
+      smaller.concat()
+    });
+    let without_byte = (0..input.len()).map(|i| {
+      let mut smaller = input.clone();
+      smaller.remove(i);
+      smaller
+    });
+    let Some((smaller, smaller_message)) = without_line
+      .chain(without_byte)
+      .find_map(|smaller| panics(&smaller).map(|message| (smaller, message)))
+    else {
+      break;
+    };
+    input = smaller;
+    message = smaller_message;
+  }
+  Crash {
+    input,
+    failure: Failure::Panic(message),
+  }
+}
+
+/// A random corpus entry with one to four random edits, or a fresh random input if the corpus
+/// is empty.
+pub fn mutate(corpus: &[Vec<u8>], rng: &mut impl Rng) -> Vec<u8> {
+  let Some(seed) = corpus.choose(rng) else {
+    return (0..rng.random_range(0..64))
+      .map(|_| rng.random_range(b' '..=b'~'))
+      .collect();
+  };
+  let mut input = seed.clone();
+  for _ in 0..rng.random_range(1..=4) {
+    edit(&mut input, corpus, rng);
+  }
+  input
+}
+
+/// One random edit of `input`, drawing new material from `corpus`, which is not empty.
+fn edit(input: &mut Vec<u8>, corpus: &[Vec<u8>], rng: &mut impl Rng) {
+  let donor = corpus.choose(rng).expect("the corpus is not empty");
+  match rng.random_range(0..8) {
+    // Replace a byte with one from another input.
+    0 if !input.is_empty() && !donor.is_empty() => {
+      let i = rng.random_range(0..input.len());
+      input[i] = *donor.choose(rng).unwrap();
+    }
+    // Insert a byte that often has a meaning: a separator, a sign or a digit.
+    1 => {
+      let i = rng.random_range(0..=input.len());
+      input.insert(
+        i,
+        *b"\n \t-+0123456789,;:=@#.()[]{}<>\xff".choose(rng).unwrap(),
+      );
+    }
+    // Delete a run of bytes.
+    2 if !input.is_empty() => {
+      let start = rng.random_range(0..input.len());
+      let end = (start + rng.random_range(1..=16)).min(input.len());
+      input.drain(start..end);
+    }
+    // Replace a number with an interesting one.
+    3 => {
+      if let Some(&(start, end)) = number_spans(input).choose(rng) {
+        let replacement = INTERESTING_NUMBERS.choose(rng).unwrap();
+        input.splice(start..end, replacement.bytes());
+      }
+    }
+    // Drop, duplicate or swap whole lines.
+    4..=6 => {
+      let mut lines: Vec<&[u8]> = input.split_inclusive(|&b| b == b'\n').collect();
+      if lines.is_empty() {
+        return;
+      }
+      let (i, j) = (
+        rng.random_range(0..lines.len()),
+        rng.random_range(0..lines.len()),
+      );
+      match rng.random_range(0..3) {
+        0 => {
+          lines.remove(i);
+        }
+        1 => lines.insert(j, lines[i]),
+        _ => lines.swap(i, j),
+      }
+      *input = lines.concat();
+    }
+    // Splice in a piece of another input.
+    _ => {
+      let start = rng.random_range(0..=donor.len());
+      let end = (start + rng.random_range(0..64)).min(donor.len());
+      let i = rng.random_range(0..=input.len());
+      input.splice(i..i, donor[start..end].iter().copied());
+    }
+  }
+}
+
+/// Byte ranges of the runs of ASCII digits in `input`, with a leading minus sign.
+fn number_spans(input: &[u8]) -> Vec<(usize, usize)> {
+  let mut spans = Vec::new();
+  let mut i = 0;
+  while i < input.len() {
+    if input[i].is_ascii_digit() {
+      let start = if i > 0 && input[i - 1] == b'-' {
+        i - 1
+      } else {
+        i
+      };
+      while i < input.len() && input[i].is_ascii_digit() {
+        i += 1;
+      }
+      spans.push((start, i));
+    } else {
+      i += 1;
+    }
+  }
+  spans
+}
+
+/// The corpus of `day`: every file in `dir/dNN`, in name order. A missing directory is an empty
+/// corpus.
+pub fn load_corpus(dir: &Path, day: u8) -> Result<Vec<Vec<u8>>, String> {
+  let dir = dir.join(format!("d{:02}", day));
+  let Ok(entries) = fs::read_dir(&dir) else {
+    return Ok(Vec::new());
+  };
+  let mut paths: Vec<PathBuf> = entries
+    .filter_map(|entry| entry.ok())
+    .map(|entry| entry.path())
+    .filter(|path| path.is_file())
+    .collect();
+  paths.sort();
+  paths
+    .iter()
+    .map(|path| fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e)))
+    .collect()
+}
+
+/// Writes an input that broke `day` to `dir/dNN-<hash>.txt` and returns its path.
+pub fn save_artifact(dir: &Path, day: u8, input: &[u8]) -> Result<PathBuf, String> {
+  let mut hasher = DefaultHasher::new();
+  input.hash(&mut hasher);
+  let path = dir.join(format!("d{:02}-{:016x}.txt", day, hasher.finish()));
+  fs::create_dir_all(dir)
+    .and_then(|()| fs::write(&path, input))
+    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+  Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Solution, days};
+  use rand::SeedableRng;
+  use rand::rngs::StdRng;
+
+  /// Generous for slow machines, with a step budget so that searches give up quickly.
+  const OPTIONS: Options = Options {
+    runs: 200,
+    timeout: Duration::from_secs(10),
+    steps: Some(200),
+  };
+
+  /// Panics on any input containing `boom`.
+  struct Fragile;
+
+  impl Solution for Fragile {
+    const DAY: u8 = 26;
+    const TITLE: &'static str = "Fragile";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+      assert!(!input.contains("boom"), "boom");
+      Ok(Fragile)
+    }
+
+    fn part1(&self) -> Answer {
+      Answer::None
+    }
+
+    fn part2(&self) -> Answer {
+      Answer::None
+    }
+  }
+
+  #[test]
+  fn test_corpus_is_handled() {
+    // The samples have answers, which a randomized search may take more steps to find.
+    let options = Options {
+      steps: None,
+      ..OPTIONS
+    };
+    for day in days::ALL {
+      // Days added by `aoc new` have no corpus until one is checked in.
+      if !Path::new(CORPUS_DIR)
+        .join(format!("d{:02}", day.number))
+        .is_dir()
+      {
+        continue;
+      }
+      let corpus = load_corpus(Path::new(CORPUS_DIR), day.number).unwrap();
+      assert!(!corpus.is_empty(), "day {} has no corpus", day.number);
+      for input in corpus {
+        assert!(
+          check(day, &input, options).is_ok(),
+          "day {}:\n{}",
+          day.number,
+          String::from_utf8_lossy(&input)
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn test_mutations_are_handled() {
+    for day in days::ALL {
+      let corpus = load_corpus(Path::new(CORPUS_DIR), day.number).unwrap();
+      let report = fuzz(day, &corpus, OPTIONS, &mut StdRng::seed_from_u64(2023));
+      if let Some(crash) = report.crash {
+        panic!(
+          "day {}: {:?}\n{}",
+          day.number,
+          crash.failure,
+          String::from_utf8_lossy(&crash.input)
+        );
+      }
+      assert_eq!(report.runs, corpus.len() + OPTIONS.runs);
+    }
+  }
+
+  #[test]
+  fn test_invalid_input_is_a_parse_error() {
+    let day = *days::get(19).unwrap();
+    let target = |data: &[u8]| target(day, data, Budget::UNLIMITED);
+    assert!(target(b"in{x<1:nowhere,A}\n\n{x=1,m=1,a=1,s=1}\n").is_err());
+    assert!(target(b"in{x<1:in,A}\n\n{x=1,m=1,a=1,s=1}\n").is_err());
+    assert!(target(b"\xff\xfe").is_err());
+  }
+
+  #[test]
+  fn test_panics_are_minimized() {
+    let day = Day::of::<Fragile>();
+    let crash = minimize(day, b"safe\nka-boom!\nsafe\n".to_vec(), OPTIONS);
+    assert_eq!(crash.input, b"boom");
+    assert_eq!(crash.failure, Failure::Panic("boom".to_string()));
+
+    let report = fuzz(
+      day,
+      &[b"boom".to_vec()],
+      Options::default(),
+      &mut StdRng::seed_from_u64(2023),
+    );
+    assert_eq!(report.runs, 1);
+  }
+
+  #[test]
+  fn test_number_spans() {
+    assert_eq!(number_spans(b"a12 -3,x-"), vec![(1, 3), (4, 6)]);
+  }
+}
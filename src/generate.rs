@@ -0,0 +1,954 @@
+//! Random puzzle inputs, for stress, fuzz and differential testing and for benchmarks.
+//!
+//! [`input`] makes a random input for a day. Inputs are structurally valid: they parse, and
+//! they keep the promises the puzzle text makes and the solutions rely on, such as a single
+//! pipe loop on day 10 or a rock that hits every hailstone on day 24. `size` scales an input
+//! roughly linearly; each day's generator says what it counts. The helpers that build one piece
+//! of an input are public, so that tests can generate and shrink that piece directly.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom, index};
+
+/// Size of the inputs `aoc generate` makes when none is given: small enough for every day to
+/// be solved quickly, even by a brute-force reference.
+pub const DEFAULT_SIZE: usize = 20;
+
+/// A random input of about `size` for `day`, or `None` if the day has no generator.
+pub fn input(day: u8, size: usize, rng: &mut impl Rng) -> Option<String> {
+  Some(match day {
+    1 => day01(rng, size),
+    2 => day02(rng, size),
+    3 => day03(rng, size),
+    4 => day04(rng, size),
+    5 => day05(rng, size),
+    6 => day06(rng, size),
+    7 => day07(rng, size),
+    8 => day08(rng, size),
+    9 => day09(rng, size),
+    10 => day10(rng, size),
+    11 => day11(rng, size),
+    12 => day12(rng, size),
+    13 => day13(rng, size),
+    14 => day14(rng, size),
+    15 => day15(rng, size),
+    16 => day16(rng, size),
+    17 => day17(rng, size),
+    18 => day18(rng, size),
+    19 => day19(rng, size),
+    20 => day20(rng, size),
+    21 => day21(rng, size),
+    22 => day22(rng, size),
+    23 => day23(rng, size),
+    24 => day24(rng, size),
+    25 => day25(rng, size),
+    _ => return None,
+  })
+}
+
+/// `size` calibration lines, each with at least one digit and some spelled-out digits.
+pub fn day01(rng: &mut impl Rng, size: usize) -> String {
+  const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+  ];
+  lines((0..size).map(|_| {
+    let mut line = String::new();
+    for _ in 0..rng.random_range(1..=6) {
+      match rng.random_range(0..3) {
+        0 => line.push(letter(rng)),
+        1 => line.push(rng.random_range(b'1'..=b'9') as char),
+        _ => line.push_str(WORDS.choose(rng).unwrap()),
+      }
+    }
+    // Part 1 needs a digit on every line.
+    if !line.contains(|c: char| c.is_ascii_digit()) {
+      let at = rng.random_range(0..=line.len());
+      line.insert(at, rng.random_range(b'1'..=b'9') as char);
+    }
+    line
+  }))
+}
+
+/// `size` games of up to six draws.
+pub fn day02(rng: &mut impl Rng, size: usize) -> String {
+  lines((1..=size).map(|id| {
+    let draws: Vec<String> = (0..rng.random_range(1..=6))
+      .map(|_| {
+        let mut colors = ["red", "green", "blue"];
+        colors.shuffle(rng);
+        let count = rng.random_range(1..=3);
+        colors[..count]
+          .iter()
+          .map(|color| format!("{} {}", rng.random_range(1..=20), color))
+          .collect::<Vec<_>>()
+          .join(", ")
+      })
+      .collect();
+    format!("Game {}: {}", id, draws.join("; "))
+  }))
+}
+
+/// A square schematic `size` characters wide with numbers of up to three digits.
+pub fn day03(rng: &mut impl Rng, size: usize) -> String {
+  const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '-', '&'];
+  let side = size.max(1);
+  lines((0..side).map(|_| {
+    let mut row = String::new();
+    while row.len() < side {
+      match rng.random_range(0..10) {
+        0 | 1 => {
+          row.push_str(&rng.random_range(1..1000).to_string());
+          row.push('.');
+        }
+        2 => row.push(*SYMBOLS.choose(rng).unwrap()),
+        _ => row.push('.'),
+      }
+    }
+    row.truncate(side);
+    row
+  }))
+}
+
+/// `size` scratchcards with five winning numbers and eight numbers each, as in the example.
+pub fn day04(rng: &mut impl Rng, size: usize) -> String {
+  lines((1..=size).map(|id| {
+    let mut numbers = |count| {
+      index::sample(rng, 99, count)
+        .iter()
+        .map(|i| format!("{:>2}", i + 1))
+        .collect::<Vec<_>>()
+        .join(" ")
+    };
+    let winning = numbers(5);
+    format!("Card {:>3}: {} | {}", id, winning, numbers(8))
+  }))
+}
+
+/// `size` seed ranges, and maps that each split the numbers below `100 * size` into about
+/// `size` ranges.
+pub fn day05(rng: &mut impl Rng, size: usize) -> String {
+  const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+  ];
+  let size = size.max(1) as u64;
+  let limit = 100 * size;
+
+  let seeds: Vec<String> = (0..size)
+    .map(|_| {
+      let start = rng.random_range(0..limit);
+      format!("{} {}", start, rng.random_range(1..=limit - start))
+    })
+    .collect();
+  let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+  for pair in CATEGORIES.windows(2) {
+    // Source ranges never overlap; some numbers are left to map to themselves.
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < limit {
+      let length = rng.random_range(1..=200).min(limit - start);
+      if rng.random_bool(0.7) {
+        let destination = rng.random_range(0..limit);
+        ranges.push(format!("{} {} {}", destination, start, length));
+      }
+      start += length;
+    }
+    ranges.shuffle(rng);
+    sections.push(format!(
+      "{}-to-{} map:\n{}",
+      pair[0],
+      pair[1],
+      ranges.join("\n")
+    ));
+  }
+  sections.join("\n\n") + "\n"
+}
+
+/// `size` races, at most four, since part 2 joins their digits into one number.
+pub fn day06(rng: &mut impl Rng, size: usize) -> String {
+  let (times, distances): (Vec<u64>, Vec<u64>) = (0..size.clamp(1, 4))
+    .map(|_| {
+      let time = rng.random_range(7..100);
+      // A record below the best distance, so that every race can be won.
+      (time, rng.random_range(0..time * time / 4))
+    })
+    .unzip();
+  let row = |label: &str, values: &[u64]| {
+    let values: String = values.iter().map(|v| format!("{:>5}", v)).collect();
+    format!("{:<9}{}", label, values)
+  };
+  lines([row("Time:", &times), row("Distance:", &distances)])
+}
+
+/// `size` hands, drawn from few labels so that every kind of hand turns up.
+pub fn day07(rng: &mut impl Rng, size: usize) -> String {
+  const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+  ];
+  lines((0..size).map(|_| {
+    let count = rng.random_range(1..=5);
+    let labels: Vec<char> = CARDS.choose_multiple(rng, count).copied().collect();
+    let hand: String = (0..5).map(|_| *labels.choose(rng).unwrap()).collect();
+    format!("{} {}", hand, rng.random_range(1..=1000))
+  }))
+}
+
+/// `size` instructions and up to six ghosts, each walking them at most seven times per loop.
+pub fn day08(rng: &mut impl Rng, size: usize) -> String {
+  let ghosts = rng.random_range(1..=6);
+  let (instructions, ghosts) = ghost_network(rng, size.max(1), ghosts, 7);
+  let mut nodes = ghosts.concat();
+  nodes.shuffle(rng);
+  format!("{}\n\n{}\n", instructions, nodes.join("\n"))
+}
+
+/// A day 8 network shaped like the puzzle's, as its instructions and each ghost's node lines.
+///
+/// Each of the `ghosts` ghosts reaches its only `Z` node after walking the instructions a whole
+/// number of times, at most `laps`, and then loops back to the node after its start, so it is on
+/// `Z` exactly at the multiples of its loop's length. The first ghost runs from `AAA` to `ZZZ`
+/// for part 1. There are at most 26 ghosts.
+pub fn ghost_network(
+  rng: &mut impl Rng,
+  instructions: usize,
+  ghosts: usize,
+  laps: usize,
+) -> (String, Vec<Vec<String>>) {
+  let instructions: String = (0..instructions.max(1))
+    .map(|_| if rng.random_bool(0.5) { 'L' } else { 'R' })
+    .collect();
+  let ghosts = (0..ghosts.min(26) as u8)
+    .map(|ghost| {
+      let letter = (b'A' + ghost) as char;
+      let period = instructions.len() * rng.random_range(1..=laps.max(1));
+      let names: Vec<String> = (0..=period)
+        .map(|t| match t {
+          0 if ghost == 0 => "AAA".to_string(),
+          t if t == period && ghost == 0 => "ZZZ".to_string(),
+          0 => format!("{}0A", letter),
+          t if t == period => format!("{}0Z", letter),
+          t => format!("{}{:02}", letter, t),
+        })
+        .collect();
+      (0..=period)
+        .map(|t| {
+          let next = &names[if t == period { 1 } else { t + 1 }];
+          let decoy = &names[rng.random_range(0..=period)];
+          let (left, right) = match instructions.as_bytes()[t % instructions.len()] {
+            b'L' => (next, decoy),
+            _ => (decoy, next),
+          };
+          format!("{} = ({}, {})", names[t], left, right)
+        })
+        .collect()
+    })
+    .collect();
+  (instructions, ghosts)
+}
+
+/// `size` histories, each the values of a polynomial of degree at most four.
+pub fn day09(rng: &mut impl Rng, size: usize) -> String {
+  lines((0..size).map(|_| {
+    let coefficients: Vec<i64> = (0..rng.random_range(1..=5))
+      .map(|_| rng.random_range(-5..=5))
+      .collect();
+    (0..rng.random_range(6..=21))
+      .map(|x: i64| {
+        let value: i64 = coefficients.iter().rev().fold(0, |sum, c| sum * x + c);
+        value.to_string()
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+  }))
+}
+
+/// A square field `size` tiles wide with one pipe loop through the start among stray pipes.
+///
+/// The loop runs around a random region of the squares between tile centers, grown one square
+/// at a time so that it never gets a hole or two squares touching only at a corner. Its outline
+/// is then a simple closed path through tile centers, which is exactly a loop of pipes.
+pub fn day10(rng: &mut impl Rng, size: usize) -> String {
+  let side = size.max(3);
+  let squares = side - 1;
+  let mut region = vec![vec![false; squares]; squares];
+  region[rng.random_range(0..squares)][rng.random_range(0..squares)] = true;
+  let target = rng.random_range(1..=(squares * squares / 2).max(1));
+  let mut grown = 1;
+  for _ in 0..4 * squares * squares {
+    if grown >= target {
+      break;
+    }
+    let (x, y) = (rng.random_range(0..squares), rng.random_range(0..squares));
+    if !region[y][x] && can_grow(&region, x, y) {
+      region[y][x] = true;
+      grown += 1;
+    }
+  }
+
+  let inside = |x: usize, y: usize, dx: isize, dy: isize| {
+    let (x, y) = (x as isize + dx, y as isize + dy);
+    (0..squares as isize).contains(&x)
+      && (0..squares as isize).contains(&y)
+      && region[y as usize][x as usize]
+  };
+  let mut tiles: Vec<Vec<char>> = (0..side)
+    .map(|y| {
+      (0..side)
+        .map(|x| {
+          // The outline runs between a tile and its neighbor where the squares on either side
+          // of that step differ.
+          let up = inside(x, y, -1, -1) != inside(x, y, 0, -1);
+          let down = inside(x, y, -1, 0) != inside(x, y, 0, 0);
+          let left = inside(x, y, -1, -1) != inside(x, y, -1, 0);
+          let right = inside(x, y, 0, -1) != inside(x, y, 0, 0);
+          match (up, down, left, right) {
+            (true, true, false, false) => '|',
+            (false, false, true, true) => '-',
+            (true, false, false, true) => 'L',
+            (true, false, true, false) => 'J',
+            (false, true, true, false) => '7',
+            (false, true, false, true) => 'F',
+            _ => ' ',
+          }
+        })
+        .collect()
+    })
+    .collect();
+
+  let on_loop: Vec<(usize, usize)> = (0..side)
+    .flat_map(|y| (0..side).map(move |x| (x, y)))
+    .filter(|&(x, y)| tiles[y][x] != ' ')
+    .collect();
+  let &(start_x, start_y) = on_loop.choose(rng).unwrap();
+  tiles[start_y][start_x] = 'S';
+  for (y, row) in tiles.iter_mut().enumerate() {
+    for (x, tile) in row.iter_mut().enumerate() {
+      if *tile != ' ' {
+        continue;
+      }
+      // Stray pipes next to the start must not connect to it.
+      let next_to_start = x.abs_diff(start_x) + y.abs_diff(start_y) == 1;
+      *tile = if next_to_start || rng.random_bool(0.5) {
+        '.'
+      } else {
+        *['|', '-', 'L', 'J', '7', 'F'].choose(rng).unwrap()
+      };
+    }
+  }
+  lines(tiles.into_iter().map(String::from_iter))
+}
+
+/// Whether adding square `(x, y)` to `region` keeps it one piece without holes or squares that
+/// touch only at a corner: the region's squares around it must form a single run that starts
+/// and ends beside it rather than diagonally.
+fn can_grow(region: &[Vec<bool>], x: usize, y: usize) -> bool {
+  const RING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+  ];
+  let around: Vec<bool> = RING
+    .iter()
+    .map(|&(dx, dy)| {
+      let (x, y) = (x as isize + dx, y as isize + dy);
+      y >= 0
+        && x >= 0
+        && region
+          .get(y as usize)
+          .and_then(|row| row.get(x as usize))
+          .copied()
+          .unwrap_or(false)
+    })
+    .collect();
+  let starts: Vec<usize> = (0..8)
+    .filter(|&i| around[i] && !around[(i + 7) % 8])
+    .collect();
+  let ends: Vec<usize> = (0..8)
+    .filter(|&i| around[i] && !around[(i + 1) % 8])
+    .collect();
+  // Odd positions in the ring are the squares beside this one.
+  starts.len() == 1 && starts[0] % 2 == 1 && ends[0] % 2 == 1
+}
+
+/// A square image `size` pixels wide with a few galaxies.
+pub fn day11(rng: &mut impl Rng, size: usize) -> String {
+  let side = size.max(1);
+  lines((0..side).map(|_| {
+    (0..side)
+      .map(|_| if rng.random_bool(0.05) { '#' } else { '.' })
+      .collect::<String>()
+  }))
+}
+
+/// `size` condition records of up to 20 springs.
+pub fn day12(rng: &mut impl Rng, size: usize) -> String {
+  lines((0..size).map(|_| {
+    let length = rng.random_range(1..=20);
+    let (springs, groups) = spring_row(rng, length);
+    let groups: Vec<String> = groups.iter().map(usize::to_string).collect();
+    format!("{} {}", springs, groups.join(","))
+  }))
+}
+
+/// A day 12 row of `length` springs with its group sizes.
+///
+/// Part of a known row is hidden behind `?`, so most rows have an arrangement; now and then a
+/// group is made one longer, which may leave none. Every row has at least one group.
+pub fn spring_row(rng: &mut impl Rng, length: usize) -> (String, Vec<usize>) {
+  let mut row: Vec<u8> = (0..length.max(1))
+    .map(|_| if rng.random_bool(0.5) { b'#' } else { b'.' })
+    .collect();
+  if !row.contains(&b'#') {
+    let i = rng.random_range(0..row.len());
+    row[i] = b'#';
+  }
+  let mut groups: Vec<usize> = row
+    .split(|&c| c == b'.')
+    .filter(|group| !group.is_empty())
+    .map(<[u8]>::len)
+    .collect();
+  if rng.random_bool(0.2) {
+    let i = rng.random_range(0..groups.len());
+    groups[i] += 1;
+  }
+  let springs = row
+    .iter()
+    .map(|&c| if rng.random_bool(0.5) { '?' } else { c as char })
+    .collect();
+  (springs, groups)
+}
+
+/// `size` patterns, each with a perfect reflection and one more that a single smudge spoils.
+///
+/// The pattern mirrors its columns across one line and its rows across another, then has a
+/// pixel flipped in a row the row reflection does not reach, leaving that reflection perfect
+/// and the column one off by the smudge. Half the patterns are turned on their side.
+pub fn day13(rng: &mut impl Rng, size: usize) -> String {
+  let patterns: Vec<String> = (0..size.max(1))
+    .map(|_| {
+      let (height, width) = (rng.random_range(3..=17), rng.random_range(2..=17));
+      let column_line = rng.random_range(1..width);
+      let row_line = loop {
+        // An even height has a middle line that would reach every row.
+        let line = rng.random_range(1..height);
+        if 2 * line != height {
+          break line;
+        }
+      };
+
+      let mut pattern: Vec<Vec<bool>> = (0..height)
+        .map(|_| {
+          let mut row: Vec<bool> = (0..width).map(|_| rng.random_bool(0.5)).collect();
+          mirror(&mut row, column_line);
+          row
+        })
+        .collect();
+      mirror(&mut pattern, row_line);
+
+      let reach = row_line.min(height - row_line);
+      let column_reach = column_line.min(width - column_line);
+      let row = loop {
+        let row = rng.random_range(0..height);
+        if !(row_line - reach..row_line + reach).contains(&row) {
+          break row;
+        }
+      };
+      let column = rng.random_range(column_line - column_reach..column_line + column_reach);
+      pattern[row][column] = !pattern[row][column];
+
+      if rng.random_bool(0.5) {
+        pattern = (0..width)
+          .map(|x| pattern.iter().map(|row| row[x]).collect())
+          .collect();
+      }
+      pattern
+        .iter()
+        .map(|row| {
+          row
+            .iter()
+            .map(|&rock| if rock { '#' } else { '.' })
+            .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+    })
+    .collect();
+  patterns.join("\n\n") + "\n"
+}
+
+/// Copies the items before `line` onto those after it, as far as both sides reach.
+fn mirror<T: Clone>(items: &mut [T], line: usize) {
+  for i in 0..line.min(items.len() - line) {
+    items[line + i] = items[line - 1 - i].clone();
+  }
+}
+
+/// A square platform `size` tiles wide with round and cube-shaped rocks.
+pub fn day14(rng: &mut impl Rng, size: usize) -> String {
+  let side = size.max(1);
+  lines((0..side).map(|_| {
+    (0..side)
+      .map(|_| match rng.random_range(0..20) {
+        0..=3 => 'O',
+        4..=6 => '#',
+        _ => '.',
+      })
+      .collect::<String>()
+  }))
+}
+
+/// `size` initialization steps on short labels, so that lenses are often replaced or removed.
+pub fn day15(rng: &mut impl Rng, size: usize) -> String {
+  let steps: Vec<String> = (0..size.max(1))
+    .map(|_| {
+      let label: String = (0..rng.random_range(1..=3)).map(|_| letter(rng)).collect();
+      if rng.random_bool(0.7) {
+        format!("{}={}", label, rng.random_range(1..=9))
+      } else {
+        format!("{}-", label)
+      }
+    })
+    .collect();
+  steps.join(",") + "\n"
+}
+
+/// A square contraption `size` tiles wide with mirrors and splitters.
+pub fn day16(rng: &mut impl Rng, size: usize) -> String {
+  let side = size.max(1);
+  lines((0..side).map(|_| {
+    (0..side)
+      .map(|_| match rng.random_range(0..20) {
+        0 => '/',
+        1 => '\\',
+        2 => '|',
+        3 => '-',
+        _ => '.',
+      })
+      .collect::<String>()
+  }))
+}
+
+/// A square city `size` blocks wide, and at least five, the fewest an ultra crucible can
+/// cross.
+pub fn day17(rng: &mut impl Rng, size: usize) -> String {
+  let side = size.max(5);
+  lines((0..side).map(|_| {
+    (0..side)
+      .map(|_| rng.random_range(b'1'..=b'9') as char)
+      .collect::<String>()
+  }))
+}
+
+/// A dig plan around `size` columns, whose colors dig the same outline scaled up.
+pub fn day18(rng: &mut impl Rng, size: usize) -> String {
+  let columns = lagoon(rng, size.max(1));
+  dig_plan(&columns, rng.random_range(1000..10000))
+}
+
+/// A lagoon made of side-by-side columns, each `(width, top, bottom)`, for [`dig_plan`].
+/// Neighboring columns overlap, so the outline is a simple polygon.
+pub fn lagoon(rng: &mut impl Rng, columns: usize) -> Vec<(i64, i64, i64)> {
+  let mut lagoon: Vec<(i64, i64, i64)> = Vec::new();
+  for _ in 0..columns {
+    let top = match lagoon.last() {
+      Some(&(_, top, bottom)) => rng.random_range(top - 4..bottom),
+      None => rng.random_range(0..6),
+    };
+    let lowest_top = lagoon
+      .last()
+      .map_or(top, |&(_, previous, _)| top.max(previous));
+    let bottom = rng.random_range(lowest_top + 1..=lowest_top + 6);
+    lagoon.push((rng.random_range(1..=4), top, bottom));
+  }
+  lagoon
+}
+
+/// The dig plan that goes clockwise around `columns`: along their tops, down the right side,
+/// back along their bottoms and up the left side. The colors encode the same plan with every
+/// distance multiplied by `scale`.
+pub fn dig_plan(columns: &[(i64, i64, i64)], scale: i64) -> String {
+  let mut moves = Vec::new();
+  let vertical = |from: i64, to: i64| (if to < from { 'U' } else { 'D' }, (to - from).abs());
+  for (i, &(width, top, _)) in columns.iter().enumerate() {
+    moves.push(('R', width));
+    let next_top = columns.get(i + 1).map_or(columns[i].2, |next| next.1);
+    moves.push(vertical(top, next_top));
+  }
+  for (i, &(width, _, bottom)) in columns.iter().enumerate().rev() {
+    moves.push(('L', width));
+    let next_bottom = if i == 0 {
+      columns[0].1
+    } else {
+      columns[i - 1].2
+    };
+    moves.push(vertical(bottom, next_bottom));
+  }
+
+  lines(
+    moves
+      .into_iter()
+      .filter(|&(_, distance)| distance > 0)
+      .map(|(direction, distance)| {
+        let digit = "RDLU".find(direction).unwrap();
+        format!(
+          "{} {} (#{:05x}{})",
+          direction,
+          distance,
+          distance * scale,
+          digit
+        )
+      }),
+  )
+}
+
+/// `size` workflows forming a tree under `in`, and `size` parts.
+pub fn day19(rng: &mut impl Rng, size: usize) -> String {
+  let count = size.max(1);
+  let mut names = vec!["in".to_string()];
+  let mut taken: HashSet<String> = ["in", "A", "R"].map(String::from).into();
+  while names.len() < count {
+    let name: String = (0..rng.random_range(2..=3)).map(|_| letter(rng)).collect();
+    if taken.insert(name.clone()) {
+      names.push(name);
+    }
+  }
+
+  // Every workflow but `in` is sent to by an earlier one, so none is unreachable and there are
+  // no cycles.
+  let mut destinations: Vec<Vec<String>> = vec![Vec::new(); count];
+  for (i, name) in names.iter().enumerate().skip(1) {
+    destinations[rng.random_range(0..i)].push(name.clone());
+  }
+  let mut lines = Vec::new();
+  for (name, mut destinations) in names.iter().zip(destinations) {
+    for _ in 0..rng.random_range(1..=2) {
+      destinations.push(if rng.random_bool(0.5) { "A" } else { "R" }.to_string());
+    }
+    destinations.shuffle(rng);
+    let fallback = destinations.pop().unwrap();
+    let mut rules: Vec<String> = destinations
+      .iter()
+      .map(|destination| {
+        format!(
+          "{}{}{}:{}",
+          ['x', 'm', 'a', 's'].choose(rng).unwrap(),
+          if rng.random_bool(0.5) { '<' } else { '>' },
+          rng.random_range(1..=4000),
+          destination
+        )
+      })
+      .collect();
+    rules.push(fallback);
+    lines.push(format!("{}{{{}}}", name, rules.join(",")));
+  }
+  lines.shuffle(rng);
+
+  let parts: Vec<String> = (0..count)
+    .map(|_| {
+      let [x, m, a, s] = [(); 4].map(|_| rng.random_range(1..=4000));
+      format!("{{x={},m={},a={},s={}}}", x, m, a, s)
+    })
+    .collect();
+  format!("{}\n\n{}\n", lines.join("\n"), parts.join("\n"))
+}
+
+/// Four counters as in the puzzle, each `size` flip-flops long (at most 12, the puzzle's).
+pub fn day20(rng: &mut impl Rng, size: usize) -> String {
+  let bits = size.clamp(1, 12) as u32;
+  let numbers: Vec<u32> = (0..4)
+    .map(|_| rng.random_range(1 << (bits - 1)..1 << bits) | 1)
+    .collect();
+  pulse_counters(&numbers)
+}
+
+/// A day 20 network shaped like the puzzle's: each number becomes a binary counter of
+/// flip-flops that resets after that many presses, and `rx` receives a low pulse when all of
+/// them reset on the same press.
+///
+/// The numbers must be odd, as in the puzzle: only then does a counter reset within the press
+/// that reaches its number, sending its high pulse and a low one right after. There are at
+/// most 26 counters.
+pub fn pulse_counters(numbers: &[u32]) -> String {
+  let letters = (b'a'..=b'z').map(char::from).take(numbers.len());
+  let starts: Vec<String> = letters.clone().map(|c| format!("{}0", c)).collect();
+  let mut lines = vec![format!("broadcaster -> {}", starts.join(", "))];
+  for (letter, &number) in letters.zip(numbers) {
+    let bits = u32::BITS - number.leading_zeros();
+    let mut resets = vec![format!("{}i", letter)];
+    for bit in 0..bits {
+      let set = number >> bit & 1 == 1;
+      let mut destinations = Vec::new();
+      if bit + 1 < bits {
+        destinations.push(format!("{}{}", letter, bit + 1));
+      }
+      if set {
+        destinations.push(format!("{}c", letter));
+      }
+      if !set || bit == 0 {
+        resets.push(format!("{}{}", letter, bit));
+      }
+      lines.push(format!("%{}{} -> {}", letter, bit, destinations.join(", ")));
+    }
+    lines.push(format!("&{}c -> {}", letter, resets.join(", ")));
+    lines.push(format!("&{}i -> all", letter));
+  }
+  lines.push("&all -> rx".to_string());
+  self::lines(lines)
+}
+
+/// A garden like the puzzle's, 131 plots wide whatever the `size`: the other widths on which
+/// part 2's 26501365 steps end at the edge of a map, 3 and 393, are too small and too large.
+pub fn day21(rng: &mut impl Rng, _size: usize) -> String {
+  lines(garden(rng, 65))
+}
+
+/// The rows of a day 21 garden `2 * half_width + 1` plots wide, with the start in the middle
+/// and its row, its column and the border kept clear, as in the puzzle.
+pub fn garden(rng: &mut impl Rng, half_width: usize) -> Vec<String> {
+  let width = 2 * half_width + 1;
+  let clear = [0, half_width, width - 1];
+  (0..width)
+    .map(|y| {
+      (0..width)
+        .map(|x| {
+          if x == half_width && y == half_width {
+            'S'
+          } else if clear.contains(&x) || clear.contains(&y) || !rng.random_bool(0.2) {
+            '.'
+          } else {
+            '#'
+          }
+        })
+        .collect()
+    })
+    .collect()
+}
+
+/// `size` bricks of up to four cubes over a 10 by 10 area, none overlapping.
+pub fn day22(rng: &mut impl Rng, size: usize) -> String {
+  let mut occupied = HashSet::new();
+  let mut bricks = Vec::new();
+  while bricks.len() < size {
+    let axis = rng.random_range(0..3);
+    let length = rng.random_range(1..=4);
+    let mut start = [
+      rng.random_range(0..10),
+      rng.random_range(0..10),
+      rng.random_range(1..=2 * size as i64 + 1),
+    ];
+    if axis < 2 {
+      start[axis] = start[axis].min(10 - length);
+    }
+    let cubes: Vec<[i64; 3]> = (0..length)
+      .map(|i| {
+        let mut cube = start;
+        cube[axis] += i;
+        cube
+      })
+      .collect();
+    if cubes.iter().any(|cube| occupied.contains(cube)) {
+      continue;
+    }
+    occupied.extend(cubes.iter().copied());
+    let [x, y, z] = start;
+    let [ex, ey, ez] = cubes[cubes.len() - 1];
+    bricks.push(format!("{},{},{}~{},{},{}", x, y, z, ex, ey, ez));
+  }
+  lines(bricks)
+}
+
+/// A maze of trails `2 * (size / 2) + 1` tiles high and wide, with about `size / 2` loops.
+///
+/// Trails meet at every other tile of every other row. Every crossing is reachable from the
+/// start by going right and down, and the slopes between crossings point that way, as in the
+/// puzzle. Part 2 takes time exponential in the number of loops.
+pub fn day23(rng: &mut impl Rng, size: usize) -> String {
+  let cells = (size / 2).max(1);
+  let side = 2 * cells + 1;
+  let mut tiles = vec![vec!['#'; side]; side];
+  tiles[0][1] = '.';
+  tiles[side - 1][side - 2] = '.';
+  let carve = |tiles: &mut Vec<Vec<char>>, x: usize, y: usize, right: bool| {
+    let (x, y) = (2 * x + 1, 2 * y + 1);
+    if right {
+      tiles[y][x + 1] = '>';
+    } else {
+      tiles[y + 1][x] = 'v';
+    }
+  };
+
+  // Each crossing is joined to the one left of or above it, a tree reaching all of them.
+  for y in 0..cells {
+    for x in 0..cells {
+      tiles[2 * y + 1][2 * x + 1] = '.';
+      match (x, y) {
+        (0, 0) => {}
+        (_, 0) => carve(&mut tiles, x - 1, y, true),
+        (0, _) => carve(&mut tiles, x, y - 1, false),
+        _ if rng.random_bool(0.5) => carve(&mut tiles, x - 1, y, true),
+        _ => carve(&mut tiles, x, y - 1, false),
+      }
+    }
+  }
+  if cells > 1 {
+    for _ in 0..size / 2 {
+      let (x, y) = (
+        rng.random_range(0..cells - 1),
+        rng.random_range(0..cells - 1),
+      );
+      carve(&mut tiles, x, y, rng.random_bool(0.5));
+    }
+  }
+  lines(tiles.into_iter().map(String::from_iter))
+}
+
+/// `size` hailstones, at least four, that a rock thrown from a random position hits at
+/// distinct times, with the rock's velocity in the range the solution searches.
+pub fn day24(rng: &mut impl Rng, size: usize) -> String {
+  let rock = [(); 3].map(|_| rng.random_range(200_000_000_000_000..400_000_000_000_000i64));
+  let rock_velocity = [(); 3].map(|_| rng.random_range(-200..=200i64));
+  let mut times = HashSet::new();
+  let mut hailstones = Vec::new();
+  while hailstones.len() < size.max(4) {
+    let time = rng.random_range(100_000_000_000..1_000_000_000_000i64);
+    let velocity = rock_velocity.map(|v| v + rng.random_range(-300..=300));
+    // Seen from the rock, every hailstone moves across X, and no two in the same direction.
+    if velocity[0] == rock_velocity[0] || !times.insert(time) {
+      continue;
+    }
+    let position: Vec<i64> = (0..3)
+      .map(|axis| rock[axis] + time * (rock_velocity[axis] - velocity[axis]))
+      .collect();
+    hailstones.push(format!(
+      "{}, {}, {} @ {}, {}, {}",
+      position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+    ));
+  }
+  lines(hailstones)
+}
+
+/// `size` components, at least ten, in two halves joined by three wires. Each half is a ring
+/// in which every component is also wired to the next but one and to a few random others, so
+/// cutting it takes at least four wires and the three between the halves are the only cut.
+pub fn day25(rng: &mut impl Rng, size: usize) -> String {
+  let size = size.max(10);
+  let mut names = HashSet::new();
+  while names.len() < size {
+    names.insert((0..3).map(|_| letter(rng)).collect::<String>());
+  }
+  // Sorted, since the set's order differs between runs of the same seed.
+  let mut names: Vec<String> = names.into_iter().collect();
+  names.sort_unstable();
+
+  let split = rng.random_range(5..=size - 5);
+  let mut wires = HashSet::new();
+  for (start, end) in [(0, split), (split, size)] {
+    let count = end - start;
+    for i in 0..count {
+      for j in [i + 1, i + 2, rng.random_range(0..count)] {
+        let j = j % count;
+        if i != j {
+          wires.insert((start + i.min(j), start + i.max(j)));
+        }
+      }
+    }
+  }
+  let left = index::sample(rng, split, 3);
+  let right = index::sample(rng, size - split, 3);
+  wires.extend(left.iter().zip(right.iter().map(|j| split + j)));
+
+  // Each component lists some of its wires, and each wire is listed once.
+  let mut wires: Vec<(usize, usize)> = wires.into_iter().collect();
+  wires.sort_unstable();
+  wires.shuffle(rng);
+  let mut listed: Vec<(usize, Vec<usize>)> = Vec::new();
+  for (a, b) in wires {
+    let (a, b) = if rng.random_bool(0.5) { (a, b) } else { (b, a) };
+    match listed.iter_mut().find(|(name, _)| *name == a) {
+      Some((_, others)) => others.push(b),
+      None => listed.push((a, vec![b])),
+    }
+  }
+  lines(listed.into_iter().map(|(name, others)| {
+    let others: Vec<&str> = others.iter().map(|&other| names[other].as_str()).collect();
+    format!("{}: {}", names[name], others.join(" "))
+  }))
+}
+
+fn letter(rng: &mut impl Rng) -> char {
+  rng.random_range(b'a'..=b'z') as char
+}
+
+/// Joins `lines` into input text ending with a newline.
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+  lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Answer, Part, days};
+  use rand::SeedableRng;
+  use rand::rngs::StdRng;
+
+  #[test]
+  fn test_inputs_parse_and_solve() {
+    let mut rng = StdRng::seed_from_u64(2023);
+    for day in days::ALL {
+      for size in [1, 5, DEFAULT_SIZE] {
+        // Days added by `aoc new` have no generator until one is written.
+        let Some(text) = input(day.number, size, &mut rng) else {
+          break;
+        };
+        let puzzle = day
+          .parse(&text)
+          .unwrap_or_else(|e| panic!("size {}: {}\n{}", size, e, text));
+        // Day 25 has no second puzzle.
+        for part in Part::BOTH
+          .into_iter()
+          .filter(|&part| day.number != 25 || part == Part::One)
+        {
+          assert_ne!(
+            puzzle.solve(part),
+            Answer::None,
+            "day {} part {}",
+            day.number,
+            part
+          );
+        }
+      }
+    }
+    assert_eq!(input(26, DEFAULT_SIZE, &mut rng), None);
+  }
+
+  #[test]
+  fn test_same_seed_same_input() {
+    for day in days::ALL {
+      let make = || input(day.number, DEFAULT_SIZE, &mut StdRng::seed_from_u64(2023));
+      assert_eq!(make(), make(), "day {}", day.number);
+    }
+  }
+
+  #[test]
+  fn test_day25_has_the_planted_cut() {
+    let mut rng = StdRng::seed_from_u64(2023);
+    let components = 30;
+    let groups = days::get(25)
+      .unwrap()
+      .solve(&day25(&mut rng, components), Part::One)
+      .unwrap();
+    assert!((5..=components - 5).any(|left| groups == (left * (components - left)) as i128));
+  }
+}
@@ -0,0 +1,294 @@
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+use crate::ParseError;
+use crate::grid::Coord;
+use crate::parse::Line;
+
+/// One of the four orthogonal directions. `y` grows downward, as in [`crate::grid::Grid`], so
+/// `Up` decreases it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+  Up,
+  Right,
+  Down,
+  Left,
+}
+
+impl Direction {
+  /// Every direction, clockwise from `Up`.
+  pub const ALL: [Direction; 4] = [
+    Direction::Up,
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+  ];
+
+  /// `U`, `D`, `L` or `R`.
+  pub fn from_letter(c: char) -> Option<Self> {
+    match c {
+      'U' => Some(Direction::Up),
+      'D' => Some(Direction::Down),
+      'L' => Some(Direction::Left),
+      'R' => Some(Direction::Right),
+      _ => None,
+    }
+  }
+
+  /// `^`, `v`, `<` or `>`.
+  pub fn from_arrow(c: char) -> Option<Self> {
+    match c {
+      '^' => Some(Direction::Up),
+      'v' => Some(Direction::Down),
+      '<' => Some(Direction::Left),
+      '>' => Some(Direction::Right),
+      _ => None,
+    }
+  }
+
+  pub fn turn_left(self) -> Self {
+    match self {
+      Direction::Up => Direction::Left,
+      Direction::Right => Direction::Up,
+      Direction::Down => Direction::Right,
+      Direction::Left => Direction::Down,
+    }
+  }
+
+  pub fn turn_right(self) -> Self {
+    match self {
+      Direction::Up => Direction::Right,
+      Direction::Right => Direction::Down,
+      Direction::Down => Direction::Left,
+      Direction::Left => Direction::Up,
+    }
+  }
+
+  pub fn reverse(self) -> Self {
+    match self {
+      Direction::Up => Direction::Down,
+      Direction::Right => Direction::Left,
+      Direction::Down => Direction::Up,
+      Direction::Left => Direction::Right,
+    }
+  }
+
+  pub fn is_vertical(self) -> bool {
+    matches!(self, Direction::Up | Direction::Down)
+  }
+
+  /// The `(dx, dy)` of one step in this direction.
+  pub fn offset(self) -> (isize, isize) {
+    match self {
+      Direction::Up => (0, -1),
+      Direction::Right => (1, 0),
+      Direction::Down => (0, 1),
+      Direction::Left => (-1, 0),
+    }
+  }
+}
+
+/// A point on an unbounded plane, with `y` growing downward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+  pub x: i64,
+  pub y: i64,
+}
+
+impl Point2 {
+  pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+  pub const fn new(x: i64, y: i64) -> Self {
+    Point2 { x, y }
+  }
+
+  pub fn manhattan(self, other: Point2) -> i64 {
+    (self.x - other.x).abs() + (self.y - other.y).abs()
+  }
+
+  /// The point `distance` steps from this one in `direction`.
+  pub fn step(self, direction: Direction, distance: i64) -> Point2 {
+    self + Point2::from(direction) * distance
+  }
+
+  /// The four orthogonal neighbors, clockwise from above.
+  pub fn neighbors(self) -> [Point2; 4] {
+    Direction::ALL.map(|direction| self + direction)
+  }
+}
+
+impl From<Direction> for Point2 {
+  fn from(direction: Direction) -> Self {
+    let (dx, dy) = direction.offset();
+    Point2::new(dx as i64, dy as i64)
+  }
+}
+
+impl From<Coord> for Point2 {
+  fn from(coord: Coord) -> Self {
+    Point2::new(coord.x as i64, coord.y as i64)
+  }
+}
+
+impl Add for Point2 {
+  type Output = Point2;
+
+  fn add(self, other: Point2) -> Point2 {
+    Point2::new(self.x + other.x, self.y + other.y)
+  }
+}
+
+impl Add<Direction> for Point2 {
+  type Output = Point2;
+
+  fn add(self, direction: Direction) -> Point2 {
+    self + Point2::from(direction)
+  }
+}
+
+impl AddAssign for Point2 {
+  fn add_assign(&mut self, other: Point2) {
+    *self = *self + other;
+  }
+}
+
+impl Sub for Point2 {
+  type Output = Point2;
+
+  fn sub(self, other: Point2) -> Point2 {
+    Point2::new(self.x - other.x, self.y - other.y)
+  }
+}
+
+impl Mul<i64> for Point2 {
+  type Output = Point2;
+
+  fn mul(self, factor: i64) -> Point2 {
+    Point2::new(self.x * factor, self.y * factor)
+  }
+}
+
+/// A point or vector in space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+  pub x: i64,
+  pub y: i64,
+  pub z: i64,
+}
+
+impl Point3 {
+  pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+  pub const fn new(x: i64, y: i64, z: i64) -> Self {
+    Point3 { x, y, z }
+  }
+
+  /// Parses `text`, a slice of `line`, written as `x,y,z`; spaces around the numbers are allowed.
+  pub fn parse(line: Line, text: &str) -> Result<Self, ParseError> {
+    let coords: Vec<&str> = text.split(',').collect();
+    let [x, y, z] = coords[..] else {
+      return Err(line.error(text, "expected `x,y,z`, got"));
+    };
+    Ok(Point3::new(
+      line.parse(x, "coordinate")?,
+      line.parse(y, "coordinate")?,
+      line.parse(z, "coordinate")?,
+    ))
+  }
+
+  pub fn manhattan(self, other: Point3) -> i64 {
+    (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+  }
+
+  /// The smallest of each coordinate of the two points.
+  pub fn min(self, other: Point3) -> Point3 {
+    Point3::new(
+      self.x.min(other.x),
+      self.y.min(other.y),
+      self.z.min(other.z),
+    )
+  }
+
+  /// The largest of each coordinate of the two points.
+  pub fn max(self, other: Point3) -> Point3 {
+    Point3::new(
+      self.x.max(other.x),
+      self.y.max(other.y),
+      self.z.max(other.z),
+    )
+  }
+}
+
+impl Add for Point3 {
+  type Output = Point3;
+
+  fn add(self, other: Point3) -> Point3 {
+    Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+  }
+}
+
+impl AddAssign for Point3 {
+  fn add_assign(&mut self, other: Point3) {
+    *self = *self + other;
+  }
+}
+
+impl Sub for Point3 {
+  type Output = Point3;
+
+  fn sub(self, other: Point3) -> Point3 {
+    Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+  }
+}
+
+impl Mul<i64> for Point3 {
+  type Output = Point3;
+
+  fn mul(self, factor: i64) -> Point3 {
+    Point3::new(self.x * factor, self.y * factor, self.z * factor)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::parse;
+
+  #[test]
+  fn test_direction_turns() {
+    for direction in Direction::ALL {
+      assert_eq!(direction.turn_left().turn_right(), direction);
+      assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+      assert_eq!(
+        Point2::from(direction) + Point2::from(direction.reverse()),
+        Point2::ORIGIN
+      );
+    }
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::from_letter('L'), Some(Direction::Left));
+    assert_eq!(Direction::from_arrow('v'), Some(Direction::Down));
+  }
+
+  #[test]
+  fn test_point2() {
+    let point = Point2::new(2, -3);
+    assert_eq!(point.step(Direction::Up, 4), Point2::new(2, -7));
+    assert_eq!(point + Direction::Right, Point2::new(3, -3));
+    assert_eq!(point.manhattan(Point2::ORIGIN), 5);
+    assert_eq!(point.neighbors()[0], Point2::new(2, -4));
+  }
+
+  #[test]
+  fn test_point3() {
+    let line = parse::lines("19, 13, 30 @ -2,  1, -2").next().unwrap();
+    let (position, velocity) = line.text.split_once('@').unwrap();
+    let position = Point3::parse(line, position).unwrap();
+    let velocity = Point3::parse(line, velocity).unwrap();
+    assert_eq!(position + velocity * 2, Point3::new(15, 15, 26));
+    assert_eq!(position.manhattan(Point3::ORIGIN), 62);
+    assert_eq!(
+      position.min(velocity).max(Point3::new(0, 0, 0)),
+      Point3::new(0, 1, 0)
+    );
+    assert!(Point3::parse(line, "1,2").is_err());
+  }
+}
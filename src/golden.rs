@@ -0,0 +1,113 @@
+//! Golden tests generated from the input directory.
+//!
+//! Every `dNN_simple*.txt` sample must have a `dNN_simple*.expected` sidecar (see
+//! [`crate::answers::Sidecar`]), and each answer in it becomes a test case. Adding a sample
+//! therefore means adding its sidecar, and nothing else. A sample that is still empty and has no
+//! answers, as [`crate::scaffold::new_day`] leaves it, is skipped until the example is pasted in.
+
+use std::fs;
+use std::path::Path;
+
+use crate::answers::{KnownAnswers, SIDECAR_EXTENSION, sidecar_path};
+use crate::budget::Budget;
+use crate::days;
+use crate::input::{DEFAULT_INPUT_DIR, input_path};
+use crate::scaffold::new_day;
+use crate::temp_dir;
+use crate::verify::{Check, Status, discover_inputs, verify};
+
+/// Sample inputs of every day, as `(day, name)`.
+fn samples(dir: &Path) -> Vec<(u8, String)> {
+  days::ALL
+    .iter()
+    .flat_map(|day| {
+      discover_inputs(dir, day.number)
+        .into_iter()
+        .filter(|name| name.starts_with("simple"))
+        .map(move |name| (day.number, name))
+    })
+    .collect()
+}
+
+fn sample_checks(dir: &Path) -> Vec<Check> {
+  let mut known = KnownAnswers::default();
+  known.load_sidecars(dir).unwrap();
+  let days: Vec<u8> = days::ALL.iter().map(|day| day.number).collect();
+  verify(&known, &days, dir, Budget::UNLIMITED)
+    .into_iter()
+    .filter(|check| check.input.starts_with("simple"))
+    .collect()
+}
+
+#[test]
+fn test_every_sample_has_a_sidecar() {
+  let dir = Path::new(DEFAULT_INPUT_DIR);
+  let missing: Vec<String> = samples(dir)
+    .into_iter()
+    .map(|(day, name)| sidecar_path(&input_path(dir, day, &name)))
+    .filter(|path| !path.is_file())
+    .map(|path| path.display().to_string())
+    .collect();
+  assert!(
+    missing.is_empty(),
+    "samples without a .{} file: {:?}",
+    SIDECAR_EXTENSION,
+    missing
+  );
+}
+
+/// Samples in `dir` without an expected answer, and sample answers that do not match.
+fn sample_problems(dir: &Path) -> Vec<String> {
+  let checks = sample_checks(dir);
+
+  let unchecked = samples(dir).into_iter().filter(|(day, name)| {
+    let checked = checks
+      .iter()
+      .any(|check| check.day == *day && check.input == *name && check.status != Status::Missing);
+    let placeholder =
+      fs::read_to_string(input_path(dir, *day, name)).is_ok_and(|text| text.trim().is_empty());
+    !checked && !placeholder
+  });
+  let failures = checks
+    .iter()
+    .filter(|check| !matches!(check.status, Status::Pass | Status::Missing))
+    .map(|check| {
+      format!(
+        "day {} part {} {}: {:?}",
+        check.day, check.part, check.input, check.status
+      )
+    });
+
+  unchecked
+    .map(|(day, name)| format!("day {} {} has no expected answer", day, name))
+    .chain(failures)
+    .collect()
+}
+
+#[test]
+fn test_samples_match_sidecars() {
+  let problems = sample_problems(Path::new(DEFAULT_INPUT_DIR));
+  assert!(problems.is_empty(), "{}", problems.join("\n"));
+}
+
+#[test]
+fn test_scaffolded_sample_is_skipped() {
+  let root = temp_dir("golden-scaffold");
+  fs::create_dir_all(root.join("src/days")).unwrap();
+  fs::write(
+    root.join("src/days/mod.rs"),
+    "use crate::Day;\n\npub const ALL: [Day; 0] = [];\n",
+  )
+  .unwrap();
+  new_day(&root, Some(2023), 25, "Snowverload").unwrap();
+  let dir = root.join(DEFAULT_INPUT_DIR);
+  assert_eq!(sample_problems(&dir), Vec::<String>::new());
+
+  // Once the example is in, its answers are expected.
+  fs::write(input_path(&dir, 25, "simple"), "a: b\n").unwrap();
+  assert_eq!(
+    sample_problems(&dir),
+    vec!["day 25 simple has no expected answer".to_string()]
+  );
+  fs::remove_dir_all(&root).unwrap();
+}
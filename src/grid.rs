@@ -0,0 +1,304 @@
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::Direction;
+
+/// Position of a cell: `x` counts columns from the left, `y` rows from the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+  pub x: usize,
+  pub y: usize,
+}
+
+impl Coord {
+  pub const fn new(x: usize, y: usize) -> Self {
+    Coord { x, y }
+  }
+
+  /// The coordinate `dx` columns right and `dy` rows down, or `None` if that would be left of
+  /// or above the origin.
+  pub fn offset(self, dx: isize, dy: isize) -> Option<Coord> {
+    Some(Coord::new(
+      self.x.checked_add_signed(dx)?,
+      self.y.checked_add_signed(dy)?,
+    ))
+  }
+}
+
+/// Offsets of all eight neighbors, clockwise from north.
+pub const ADJACENT: [(isize, isize); 8] = [
+  (0, -1),
+  (1, -1),
+  (1, 0),
+  (1, 1),
+  (0, 1),
+  (-1, 1),
+  (-1, 0),
+  (-1, -1),
+];
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+  /// A `width` by `height` grid of `cells`, given row by row.
+  ///
+  /// # Panics
+  ///
+  /// If there are not exactly `width * height` cells.
+  pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+    assert_eq!(
+      cells.len(),
+      width * height,
+      "a {}x{} grid needs {} cells",
+      width,
+      height,
+      width * height
+    );
+    Grid {
+      width,
+      height,
+      cells,
+    }
+  }
+
+  /// A `width` by `height` grid whose cells are `cell` of their coordinate.
+  pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Coord) -> T) -> Self {
+    let cells = (0..height)
+      .flat_map(|y| (0..width).map(move |x| Coord::new(x, y)))
+      .map(&mut cell)
+      .collect();
+    Grid::from_cells(width, height, cells)
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn contains(&self, coord: Coord) -> bool {
+    coord.x < self.width && coord.y < self.height
+  }
+
+  pub fn get(&self, coord: Coord) -> Option<&T> {
+    self
+      .contains(coord)
+      .then(|| &self.cells[coord.y * self.width + coord.x])
+  }
+
+  pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+    if self.contains(coord) {
+      Some(&mut self.cells[coord.y * self.width + coord.x])
+    } else {
+      None
+    }
+  }
+
+  /// The cell at `(x, y)` when the grid repeats infinitely in every direction.
+  pub fn get_wrapped(&self, x: i64, y: i64) -> &T {
+    let x = x.rem_euclid(self.width as i64) as usize;
+    let y = y.rem_euclid(self.height as i64) as usize;
+    &self.cells[y * self.width + x]
+  }
+
+  /// The coordinate `dx` columns right and `dy` rows down of `coord`, if it is on the grid.
+  pub fn step(&self, coord: Coord, dx: isize, dy: isize) -> Option<Coord> {
+    coord.offset(dx, dy).filter(|&next| self.contains(next))
+  }
+
+  /// The neighbor of `coord` in `direction`, if it is on the grid.
+  pub fn neighbor(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+    let (dx, dy) = direction.offset();
+    self.step(coord, dx, dy)
+  }
+
+  /// The up to four orthogonal neighbors of `coord` that are on the grid, clockwise from north.
+  pub fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+    Direction::ALL
+      .into_iter()
+      .filter_map(move |direction| self.neighbor(coord, direction))
+  }
+
+  /// The up to eight neighbors of `coord`, diagonals included, that are on the grid.
+  pub fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+    ADJACENT
+      .iter()
+      .filter_map(move |&(dx, dy)| self.step(coord, dx, dy))
+  }
+
+  pub fn row(&self, y: usize) -> &[T] {
+    &self.cells[y * self.width..(y + 1) * self.width]
+  }
+
+  pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+    &mut self.cells[y * self.width..(y + 1) * self.width]
+  }
+
+  pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+    self.cells.chunks(self.width)
+  }
+
+  /// The cells of column `x`, from top to bottom.
+  pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+    self.cells[x..].iter().step_by(self.width)
+  }
+
+  /// Every coordinate on the grid, row by row.
+  pub fn coords(&self) -> impl Iterator<Item = Coord> + use<T> {
+    let width = self.width;
+    (0..self.height).flat_map(move |y| (0..width).map(move |x| Coord::new(x, y)))
+  }
+
+  /// Every cell with its coordinate, row by row.
+  pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+    self.coords().zip(&self.cells)
+  }
+
+  /// The first coordinate, row by row, whose cell is `value`.
+  pub fn find(&self, value: &T) -> Option<Coord>
+  where
+    T: PartialEq,
+  {
+    self.find_all(value).next()
+  }
+
+  /// Every coordinate whose cell is `value`, row by row.
+  pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coord> + 'a
+  where
+    T: PartialEq,
+  {
+    self
+      .iter()
+      .filter(move |&(_, cell)| cell == value)
+      .map(|(coord, _)| coord)
+  }
+
+  pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+    Grid::from_cells(self.width, self.height, self.cells.iter().map(f).collect())
+  }
+}
+
+impl<T: Clone> Grid<T> {
+  /// A `width` by `height` grid with every cell set to `value`.
+  pub fn new(width: usize, height: usize, value: T) -> Self {
+    Grid::from_cells(width, height, vec![value; width * height])
+  }
+
+  /// The grid mirrored along its main diagonal, so rows become columns.
+  pub fn transpose(&self) -> Self {
+    Grid::from_fn(self.height, self.width, |c| {
+      self[Coord::new(c.y, c.x)].clone()
+    })
+  }
+
+  /// The grid turned a quarter clockwise, so its left column becomes the top row.
+  pub fn rotate_clockwise(&self) -> Self {
+    Grid::from_fn(self.height, self.width, |c| {
+      self[Coord::new(c.y, self.height - 1 - c.x)].clone()
+    })
+  }
+
+  /// The grid turned a quarter counterclockwise, so its top row becomes the left column.
+  pub fn rotate_counterclockwise(&self) -> Self {
+    Grid::from_fn(self.height, self.width, |c| {
+      self[Coord::new(self.width - 1 - c.y, c.x)].clone()
+    })
+  }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, coord: Coord) -> &T {
+    self.get(coord).unwrap_or_else(|| {
+      panic!(
+        "{:?} is outside a {}x{} grid",
+        coord, self.width, self.height
+      )
+    })
+  }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+  fn index_mut(&mut self, coord: Coord) -> &mut T {
+    let (width, height) = (self.width, self.height);
+    self
+      .get_mut(coord)
+      .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", coord, width, height))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// 1 2 3
+  /// 4 5 6
+  fn sample() -> Grid<u8> {
+    Grid::from_cells(3, 2, vec![1, 2, 3, 4, 5, 6])
+  }
+
+  #[test]
+  fn test_index_and_bounds() {
+    let mut grid = sample();
+    assert_eq!(grid[Coord::new(2, 1)], 6);
+    assert_eq!(grid.get(Coord::new(3, 0)), None);
+    assert_eq!(grid.step(Coord::new(0, 0), -1, 0), None);
+    assert_eq!(grid.step(Coord::new(0, 0), 1, 1), Some(Coord::new(1, 1)));
+    grid[Coord::new(0, 1)] = 9;
+    assert_eq!(grid.row(1), &[9, 5, 6]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+  }
+
+  #[test]
+  fn test_neighbors() {
+    let grid = sample();
+    assert_eq!(
+      grid.neighbors4(Coord::new(0, 0)).collect::<Vec<_>>(),
+      vec![Coord::new(1, 0), Coord::new(0, 1)]
+    );
+    assert_eq!(grid.neighbors4(Coord::new(1, 1)).count(), 3);
+    assert_eq!(grid.neighbors8(Coord::new(1, 0)).count(), 5);
+  }
+
+  #[test]
+  fn test_get_wrapped() {
+    let grid = sample();
+    assert_eq!(*grid.get_wrapped(-1, 0), 3);
+    assert_eq!(*grid.get_wrapped(4, -1), 5);
+    assert_eq!(*grid.get_wrapped(-7, 5), 6);
+  }
+
+  #[test]
+  fn test_transpose_and_rotate() {
+    let grid = sample();
+    assert_eq!(
+      grid.transpose(),
+      Grid::from_cells(2, 3, vec![1, 4, 2, 5, 3, 6])
+    );
+    assert_eq!(
+      grid.rotate_clockwise(),
+      Grid::from_cells(2, 3, vec![4, 1, 5, 2, 6, 3])
+    );
+    assert_eq!(
+      grid.rotate_counterclockwise(),
+      Grid::from_cells(2, 3, vec![3, 6, 2, 5, 1, 4])
+    );
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+  }
+
+  #[test]
+  fn test_find() {
+    let grid = Grid::from_cells(2, 2, vec!['.', '#', '#', '.']);
+    assert_eq!(grid.find(&'#'), Some(Coord::new(1, 0)));
+    assert_eq!(grid.find_all(&'#').count(), 2);
+    assert_eq!(grid.find(&'S'), None);
+  }
+}
@@ -0,0 +1,238 @@
+use std::convert::Infallible;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs};
+
+use crate::{generate, random};
+
+/// Environment variable that overrides [`DEFAULT_INPUT_DIR`].
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "input";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+  /// `dNN_full.txt` in the input directory.
+  Default,
+  /// A file path. A bare name such as `simple2` that is not an existing file resolves to
+  /// `dNN_simple2.txt` in the input directory.
+  Path(PathBuf),
+  Stdin,
+  Inline(String),
+  /// A random input of this size from [`crate::generate`], made from the seed of the enclosing
+  /// [`random::with_seed`], or else different on every load.
+  Generated(usize),
+}
+
+/// Input text after normalization, together with the file it was read from, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+  pub text: String,
+  pub path: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+  Read { path: PathBuf, error: io::Error },
+  Stdin(io::Error),
+  NoGenerator(u8),
+}
+
+impl Display for InputError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      InputError::Read { path, error } => write!(f, "Failed to read {}: {}", path.display(), error),
+      InputError::Stdin(error) => write!(f, "Failed to read standard input: {}", error),
+      InputError::NoGenerator(day) => write!(f, "Day {} has no input generator", day),
+    }
+  }
+}
+
+impl std::error::Error for InputError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      InputError::Read { error, .. } | InputError::Stdin(error) => Some(error),
+      InputError::NoGenerator(_) => None,
+    }
+  }
+}
+
+/// `-` reads standard input; anything else is a path or bare input name.
+impl FromStr for InputSource {
+  type Err = Infallible;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(match s {
+      "-" => InputSource::Stdin,
+      _ => InputSource::Path(PathBuf::from(s)),
+    })
+  }
+}
+
+/// Directory holding the `dNN_*.txt` inputs: `$AOC_INPUT_DIR`, or `input/` when unset.
+pub fn input_dir() -> PathBuf {
+  env::var_os(INPUT_DIR_VAR)
+    .filter(|dir| !dir.is_empty())
+    .map(PathBuf::from)
+    .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Path of the input named `name` (e.g. `full`, `simple2`) for `day` inside `dir`.
+pub fn input_path(dir: &Path, day: u8, name: &str) -> PathBuf {
+  dir.join(format!("d{:02}_{}.txt", day, name))
+}
+
+impl InputSource {
+  /// Whether this source can stand for a different input on each day.
+  pub fn is_per_day(&self) -> bool {
+    match self {
+      InputSource::Default | InputSource::Generated(_) => true,
+      InputSource::Path(path) => is_bare_name(path),
+      InputSource::Stdin | InputSource::Inline(_) => false,
+    }
+  }
+
+  pub fn load(&self, day: u8) -> Result<Input, InputError> {
+    self.load_from(day, &input_dir())
+  }
+
+  /// Like [`InputSource::load`], resolving default and named inputs inside `dir`.
+  pub fn load_from(&self, day: u8, dir: &Path) -> Result<Input, InputError> {
+    let (text, path) = match self {
+      InputSource::Default => {
+        let path = input_path(dir, day, "full");
+        (read_file(&path)?, Some(path))
+      }
+      InputSource::Path(path) => {
+        let path = if !path.is_file() && is_bare_name(path) {
+          input_path(dir, day, &path.to_string_lossy())
+        } else {
+          path.clone()
+        };
+        (read_file(&path)?, Some(path))
+      }
+      InputSource::Stdin => {
+        let mut text = String::new();
+        io::stdin()
+          .read_to_string(&mut text)
+          .map_err(InputError::Stdin)?;
+        (text, None)
+      }
+      InputSource::Inline(text) => (text.clone(), None),
+      InputSource::Generated(size) => (
+        generate::input(day, *size, &mut random::rng()).ok_or(InputError::NoGenerator(day))?,
+        None,
+      ),
+    };
+
+    Ok(Input {
+      text: normalize(&text),
+      path,
+    })
+  }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+  fs::read_to_string(path).map_err(|error| InputError::Read {
+    path: path.to_path_buf(),
+    error,
+  })
+}
+
+fn is_bare_name(path: &Path) -> bool {
+  let name = path.to_string_lossy();
+  !name.is_empty()
+    && name
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Normalizes line endings to `\n`, drops a byte-order mark and trailing blank lines, and ends
+/// non-empty input with exactly one newline.
+pub fn normalize(text: &str) -> String {
+  let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+  let mut lines: Vec<&str> = text
+    .split('\n')
+    .map(|line| line.strip_suffix('\r').unwrap_or(line))
+    .collect();
+  while lines.last().is_some_and(|line| line.trim().is_empty()) {
+    lines.pop();
+  }
+
+  let mut normalized = lines.join("\n");
+  if !normalized.is_empty() {
+    normalized.push('\n');
+  }
+  normalized
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_normalize() {
+    assert_eq!(normalize("a\r\nb\r\n\r\n  \n"), "a\nb\n");
+    assert_eq!(normalize("\u{feff}a\n\nb"), "a\n\nb\n");
+    assert_eq!(normalize("\n\n"), "");
+  }
+
+  #[test]
+  fn test_parse_source() {
+    assert_eq!("-".parse(), Ok(InputSource::Stdin));
+    assert_eq!(
+      "input/d01_full.txt".parse(),
+      Ok(InputSource::Path(PathBuf::from("input/d01_full.txt")))
+    );
+  }
+
+  #[test]
+  fn test_resolve_names_in_input_dir() {
+    let dir = Path::new(DEFAULT_INPUT_DIR);
+    let input = InputSource::Path(PathBuf::from("simple2"))
+      .load_from(1, dir)
+      .unwrap();
+    assert_eq!(input.path, Some(dir.join("d01_simple2.txt")));
+
+    let input = InputSource::Default.load_from(1, dir).unwrap();
+    assert_eq!(input.path, Some(dir.join("d01_full.txt")));
+
+    assert!(
+      InputSource::Default
+        .load_from(1, Path::new("missing"))
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn test_inline_is_normalized() {
+    let input = InputSource::Inline("1\r\n2\r\n\r\n".to_string())
+      .load_from(1, Path::new(DEFAULT_INPUT_DIR))
+      .unwrap();
+    assert_eq!(input.text, "1\n2\n");
+    assert_eq!(input.path, None);
+  }
+
+  #[test]
+  fn test_per_day_sources() {
+    assert!(InputSource::Default.is_per_day());
+    assert!(InputSource::Path(PathBuf::from("simple")).is_per_day());
+    assert!(!InputSource::Path(PathBuf::from("input/d01_full.txt")).is_per_day());
+    assert!(!InputSource::Stdin.is_per_day());
+    assert!(InputSource::Generated(5).is_per_day());
+  }
+
+  #[test]
+  fn test_generated_inputs() {
+    let dir = Path::new("missing");
+    let input = InputSource::Generated(5).load_from(1, dir).unwrap();
+    assert_eq!(input.text.lines().count(), 5);
+    assert_eq!(input.path, None);
+    assert!(matches!(
+      InputSource::Generated(5).load_from(26, dir),
+      Err(InputError::NoGenerator(26))
+    ));
+  }
+}
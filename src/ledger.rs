@@ -0,0 +1,212 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+use crate::remote::Verdict;
+use crate::{Answer, Part};
+
+/// Default location of the submission ledger, relative to the repository root.
+pub const DEFAULT_LEDGER_FILE: &str = "submissions.txt";
+
+/// Every answer the website has judged, one `day part verdict answer` line each, so that an
+/// answer it would reject can be refused without submitting it again.
+///
+/// Verdicts are `correct`, `wrong`, `too-high` and `too-low`. Blank lines and lines starting
+/// with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+  entries: Vec<(u8, Part, Verdict, Answer)>,
+}
+
+impl Ledger {
+  pub fn load(path: &Path) -> Result<Self, String> {
+    let text =
+      fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Self::parse(&text).map_err(|e| format!("{}:{}", path.display(), e))
+  }
+
+  pub fn parse(text: &str) -> Result<Self, String> {
+    let mut ledger = Ledger::default();
+
+    for (i, line) in text.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      let mut fields = line.splitn(4, char::is_whitespace);
+      let (Some(day), Some(part), Some(verdict), Some(answer)) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+      else {
+        return Err(format!("{}: expected `day part verdict answer`", i + 1));
+      };
+      let day = day
+        .parse()
+        .map_err(|_| format!("{}: invalid day: {}", i + 1, day))?;
+      let part = part.parse().map_err(|e| format!("{}: {}", i + 1, e))?;
+      let verdict = verdict.parse().map_err(|e| format!("{}: {}", i + 1, e))?;
+      ledger.record(day, part, verdict, answer.parse().unwrap());
+    }
+
+    Ok(ledger)
+  }
+
+  pub fn save(&self, path: &Path) -> Result<(), String> {
+    fs::write(path, self.to_string())
+      .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+  }
+
+  /// Writes down `verdict` for `answer`, returning whether it was worth keeping: only
+  /// [judgements](Verdict::is_judgement) are, and only of answers that read back the same from
+  /// the ledger (see [`Answer::round_trips`]).
+  pub fn record(&mut self, day: u8, part: Part, verdict: Verdict, answer: Answer) -> bool {
+    if !verdict.is_judgement() || !answer.round_trips() {
+      return false;
+    }
+    self.entries.push((day, part, verdict, answer));
+    true
+  }
+
+  /// Why submitting `answer` would be pointless, or `None` if the ledger cannot tell: the part is
+  /// already solved, the same answer was rejected, or a rejected number bounds it. Text that the
+  /// ledger could not record is refused as well.
+  pub fn refusal(&self, day: u8, part: Part, answer: &Answer) -> Option<String> {
+    if answer.is_none() {
+      return Some("there is no answer to submit".to_string());
+    }
+    if !answer.round_trips() {
+      return Some("the text would read back from the ledger as another answer".to_string());
+    }
+
+    let judged = || {
+      self
+        .entries
+        .iter()
+        .filter(move |(d, p, _, _)| *d == day && *p == part)
+        .map(|(_, _, verdict, answer)| (verdict, answer))
+    };
+    if let Some((_, correct)) = judged().find(|(verdict, _)| **verdict == Verdict::Correct) {
+      return Some(if correct == answer {
+        "it was already accepted".to_string()
+      } else {
+        format!("the part was already solved with {}", correct)
+      });
+    }
+    if let Some((verdict, _)) = judged().find(|(_, judged)| *judged == answer) {
+      return Some(format!("it was already rejected as {}", verdict));
+    }
+
+    let numeric = |answer: &&Answer| matches!(answer, Answer::Int(_) | Answer::UInt(_));
+    let too_high = judged()
+      .filter(|(verdict, _)| **verdict == Verdict::TooHigh)
+      .map(|(_, answer)| answer)
+      .filter(numeric)
+      .min();
+    let too_low = judged()
+      .filter(|(verdict, _)| **verdict == Verdict::TooLow)
+      .map(|(_, answer)| answer)
+      .filter(numeric)
+      .max();
+    if numeric(&answer) {
+      if let Some(high) = too_high.filter(|high| answer > *high) {
+        return Some(format!("{} was already too high", high));
+      }
+      if let Some(low) = too_low.filter(|low| answer < *low) {
+        return Some(format!("{} was already too low", low));
+      }
+    }
+    None
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+}
+
+impl Display for Ledger {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "# aoc submit ledger: day part verdict answer")?;
+    for (day, part, verdict, answer) in &self.entries {
+      writeln!(f, "{} {} {} {}", day, part, verdict, answer)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const LEDGER: &str = "\
+# aoc submit ledger: day part verdict answer
+5 1 too-high 900
+5 1 too-low 100
+5 1 wrong 400
+6 2 correct 71503
+";
+
+  #[test]
+  fn test_parse_round_trips() {
+    let ledger = Ledger::parse(LEDGER).unwrap();
+    assert_eq!(ledger.len(), 4);
+    assert_eq!(ledger.to_string(), LEDGER);
+
+    assert!(Ledger::parse("5 1 wrong").is_err());
+    assert!(Ledger::parse("5 1 rate-limited 7").is_err());
+    assert!(Ledger::parse("5 3 wrong 7").is_err());
+  }
+
+  #[test]
+  fn test_refusal() {
+    let mut ledger = Ledger::parse(LEDGER).unwrap();
+    let refusal = |ledger: &Ledger, day, part, answer: &str| {
+      ledger.refusal(day, part, &answer.parse().unwrap())
+    };
+
+    assert_eq!(refusal(&ledger, 5, Part::One, "500"), None);
+    assert!(
+      refusal(&ledger, 5, Part::One, "400")
+        .unwrap()
+        .contains("wrong")
+    );
+    assert!(
+      refusal(&ledger, 5, Part::One, "900")
+        .unwrap()
+        .contains("too-high")
+    );
+    assert!(
+      refusal(&ledger, 5, Part::One, "901")
+        .unwrap()
+        .contains("900")
+    );
+    assert!(
+      refusal(&ledger, 5, Part::One, "99")
+        .unwrap()
+        .contains("100")
+    );
+    assert_eq!(refusal(&ledger, 5, Part::One, "abc"), None);
+    assert_eq!(refusal(&ledger, 5, Part::Two, "901"), None);
+    assert!(refusal(&ledger, 6, Part::Two, "71503").is_some());
+    assert!(refusal(&ledger, 6, Part::Two, "1").is_some());
+    assert!(refusal(&ledger, 6, Part::One, "none").is_some());
+    assert!(
+      ledger
+        .refusal(5, Part::One, &Answer::from("0042"))
+        .unwrap()
+        .contains("another answer")
+    );
+    assert!(!ledger.record(5, Part::One, Verdict::Wrong, Answer::from("0042")));
+
+    assert!(!ledger.record(5, Part::One, Verdict::AlreadySolved, Answer::from(500)));
+    assert!(ledger.record(5, Part::One, Verdict::Correct, Answer::from(500)));
+    assert!(
+      refusal(&ledger, 5, Part::One, "600")
+        .unwrap()
+        .contains("500")
+    );
+  }
+}
@@ -0,0 +1,55 @@
+//! Advent of Code 2023 solutions.
+//!
+//! Each day lives in [`days`] and implements [`Solution`]. [`days::ALL`] lists every day in
+//! calendar order, so tools can run any of them through the same API.
+
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod budget;
+pub mod cycle;
+pub mod days;
+#[cfg(test)]
+mod differential;
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+#[cfg(test)]
+mod golden;
+pub mod grid;
+pub mod input;
+pub mod ledger;
+pub mod log;
+pub mod math;
+pub mod memory;
+pub mod parallel;
+pub mod parse;
+pub mod random;
+pub mod remote;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+mod solution;
+pub mod verify;
+
+pub use answer::Answer;
+pub use parse::ParseError;
+pub use solution::{Day, Part, Puzzle, Solution};
+
+#[cfg(test)]
+pub(crate) fn solve_file<S: Solution>(path: &str, part: Part) -> Answer {
+  let input = input::InputSource::Path(path.into())
+    .load(S::DAY)
+    .expect("Failed to read input file");
+  S::parse(&input.text)
+    .expect("Failed to parse input file")
+    .solve(part)
+}
+
+/// A fresh directory under the system temp dir, unique to this process and `name`.
+#[cfg(test)]
+pub(crate) fn temp_dir(name: &str) -> std::path::PathBuf {
+  let dir = std::env::temp_dir().join(format!("aoc-{}-{}", std::process::id(), name));
+  let _ = std::fs::remove_dir_all(&dir);
+  dir
+}
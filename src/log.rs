@@ -0,0 +1,85 @@
+//! Leveled logging to standard error, for tracing solver internals on demand.
+//!
+//! Nothing is logged by default. `aoc -v` turns on [`debug!`](crate::debug) messages and `-vv`
+//! [`trace!`](crate::trace) messages as well. A disabled message costs one atomic load: its
+//! arguments are not evaluated.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much detail a message carries, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+  /// Summaries of what a solver did, at most a few lines per part.
+  Debug = 1,
+  /// Individual steps, possibly many per part.
+  Trace = 2,
+}
+
+impl fmt::Display for Level {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Level::Debug => write!(f, "debug"),
+      Level::Trace => write!(f, "trace"),
+    }
+  }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// Logs messages up to `verbosity`: 0 logs nothing, 1 debug messages and 2 or more trace
+/// messages too.
+pub fn set_verbosity(verbosity: u8) {
+  VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+/// Whether messages at `level` are logged at the verbosity set with [`set_verbosity`].
+pub fn enabled(level: Level) -> bool {
+  logs(VERBOSITY.load(Ordering::Relaxed), level)
+}
+
+/// Whether `verbosity` logs messages at `level`.
+fn logs(verbosity: u8, level: Level) -> bool {
+  level as u8 <= verbosity
+}
+
+/// Writes a message from `module` to standard error. Use the macros, which skip disabled levels.
+pub fn write(level: Level, module: &str, message: fmt::Arguments) {
+  let module = module.rsplit("::").next().unwrap_or(module);
+  eprintln!("[{} {}] {}", level, module, message);
+}
+
+/// Logs a summary line when run with `-v` or more.
+#[macro_export]
+macro_rules! debug {
+  ($($arg:tt)*) => {
+    if $crate::log::enabled($crate::log::Level::Debug) {
+      $crate::log::write($crate::log::Level::Debug, module_path!(), format_args!($($arg)*));
+    }
+  };
+}
+
+/// Logs a detailed step when run with `-vv`.
+#[macro_export]
+macro_rules! trace {
+  ($($arg:tt)*) => {
+    if $crate::log::enabled($crate::log::Level::Trace) {
+      $crate::log::write($crate::log::Level::Trace, module_path!(), format_args!($($arg)*));
+    }
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // The verbosity is global and tests run in parallel, so they leave it alone.
+  #[test]
+  fn test_levels() {
+    let levels = |verbosity| (logs(verbosity, Level::Debug), logs(verbosity, Level::Trace));
+    assert_eq!(levels(0), (false, false));
+    assert_eq!(levels(1), (true, false));
+    assert_eq!(levels(2), (true, true));
+    assert_eq!(levels(u8::MAX), (true, true));
+  }
+}
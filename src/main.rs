@@ -1,3 +1,1684 @@
-fn main() {
-    println!("Hello, world!");
+use std::env;
+use std::io::ErrorKind;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use aoc2023::answers::{DEFAULT_ANSWERS_FILE, KnownAnswers, Sidecar, sidecar_path};
+use aoc2023::bench::{self, Baseline};
+use aoc2023::budget::{self, Budget, GaveUp};
+use aoc2023::input::{self, Input, InputError, InputSource};
+use aoc2023::ledger::{DEFAULT_LEDGER_FILE, Ledger};
+use aoc2023::log;
+use aoc2023::memory::{self, Bytes, Usage};
+use aoc2023::parallel::{self, Failure};
+use aoc2023::random;
+use aoc2023::remote::{self, Client, Fetch, Verdict};
+use aoc2023::report::{self, Format};
+use aoc2023::scaffold;
+use aoc2023::verify::{self, Check, Status};
+use aoc2023::{Answer, Part, days, fuzz, generate};
+
+/// Counts the allocations of `run --memory`; outside of `memory::measure` it only passes them on.
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run       Solve puzzles and print their answers
+  bench     Time parsing and each part of puzzles
+  verify    Check solutions against the known answers
+  fetch     Download personal puzzle inputs into the input directory
+  submit    Solve a part and submit the answer
+  new       Add the skeleton of a new day
+  generate  Print a random puzzle input
+  fuzz      Feed mutated inputs to puzzles to find crashes
+  list      List every available day
+  help      Show this message
+
+Global options, accepted anywhere:
+  -v, --verbose       Log what the solvers do to stderr; -vv logs every step
+
+Run options:
+  -d, --day <N>       Day to run (1-25)
+  -a, --all           Run every day
+  -p, --part <P>      Only run part 1 or 2 (default: both)
+  -i, --input <SRC>   Input file, `-` for stdin, or a name such as `simple2`
+                      for dXX_simple2.txt (default: dXX_full.txt)
+  -t, --text <TEXT>   Use TEXT itself as the input
+  -g, --generate <S>  Use a random input of size S (see generate)
+      --param <K=V>   Set a puzzle parameter, e.g. `steps=6` for day 21
+      --seed <N>      Seed for randomized solvers and generated inputs
+                      (default: a fresh one)
+  -j, --jobs <N>      Threads for --all (default: one per CPU)
+      --timeout <S>   Seconds after which searches that could run forever give
+                      up, 0 for no limit (default: 60)
+      --format <F>    `text` or `json` (default: text)
+      --memory        Report the allocations and peak memory of each part
+
+Named and default inputs are looked up in $AOC_INPUT_DIR, or input/ when unset.
+With --all, days that have no input of the given name are skipped. Parameters
+from an input's .expected file are applied before any given with --param.
+
+A single day and part prints the bare answer, and both parts print one
+`day<TAB>part<TAB>answer` line each. --all solves every part in parallel and
+prints a table of the answers and solving times, with each day's status
+against the known answers (see verify). A part that panics or runs past the
+timeout fails on its own without stopping the rest; any failure, error or
+timeout exits with an error. Searches that could run forever check the timeout
+themselves and give up with how far they got; other parts are abandoned a
+second after it. A single day gives up the same way, printing `gave up` in
+place of the answer and exiting with an error.
+
+Days 24 and 25 solve with random choices, and --generate makes random inputs.
+All of them start from the seed, which --all prints after the table and a
+single day to stderr, so that a slow or failing run can be repeated exactly
+with --seed.
+
+--format json prints a JSON array instead, with one object per part holding its
+day, part, input name, input hash, seed, answer, solving time in seconds and
+status, plus the expected answer or error message where there is one. Answers
+and seeds are strings. A single day is solved the same way.
+
+--memory adds a table with the allocations each part made, the bytes they
+asked for and the most heap the part held at once. Its last column, process
+rss, is the peak resident set size of the whole process while the part ran,
+sampled every millisecond where /proc has it, so it includes the runner and
+whatever earlier parts left behind. The JSON gains the same numbers. Since the
+resident set is shared, parts are then solved one at a time, also for a single
+day.
+
+Bench options (plus --day, --all, --part, --input, --text, --generate, --param
+and --seed):
+      --runs <N>       Timed runs per stage (default: 5)
+      --timeout <S>    Seconds after which a run of a search that could run
+                       forever gives up, failing the bench (default: 60)
+      --warmup <N>     Untimed runs before timing (default: 1)
+      --baseline <F>   Compare medians against the baseline file F
+      --threshold <P>  Percent slowdown that counts as a regression (default: 10)
+      --save <F>       Write the medians to the baseline file F, keeping other days
+
+Bench prints the median, min and max time of parsing and of each part. Against
+a baseline it adds the change in median and flags slowdowns above the
+threshold, exiting with an error if there are any.
+
+Verify options:
+  -d, --day <N>       Only verify day N (default: every day)
+      --answers <F>   Known-answers file (default: answers.txt)
+      --timeout <S>   Seconds after which searches that could run forever give
+                      up, 0 for no limit (default: 60)
+      --seed <N>      Seed for randomized solvers (default: a fresh one)
+      --format <F>    `text` or `json` (default: text)
+
+Verify checks every input with a known answer or a file in the input directory,
+printing one `day<TAB>part<TAB>input<TAB>status` line each. Parts without a
+known answer are reported as missing and not run. Sample inputs take their
+answers and parameters from the dXX_<name>.expected file next to them. The
+seed is printed after the checks. With --format json the checks are printed as
+for run.
+
+Fetch options:
+  -d, --day <N>       Day to download (1-25)
+  -a, --all           Download every day
+      --year <Y>      Year of the puzzles (default: the year in aoc.conf, or 2023)
+
+Fetch saves each input as dXX_full.txt in the input directory and skips days
+whose file already exists, so cached inputs are never downloaded again. The
+session token is read from $AOC_SESSION, or else from
+$XDG_CONFIG_HOME/aoc/session (~/.config/aoc/session). $AOC_BASE_URL replaces
+https://adventofcode.com, e.g. to use a local server; https needs curl. The
+year comes from a `year = Y` line in aoc.conf in the current directory, which
+new writes.
+
+Submit options (plus --input, --text, --param and --seed):
+  -d, --day <N>       Day to submit (1-25)
+  -p, --part <P>      Part to submit, 1 or 2
+      --ledger <F>    Ledger of judged answers (default: submissions.txt)
+      --year <Y>      Year of the puzzle (default: the year in aoc.conf, or 2023)
+      --timeout <S>   Seconds after which searches that could run forever give
+                      up, 0 for no limit (default: 60)
+
+Submit solves the part, posts the answer with the same session token, base URL
+and year as fetch, and prints `day<TAB>part<TAB>answer<TAB>verdict`. Every verdict
+is written to the ledger, and an answer the ledger shows to be wrong, because
+it was rejected before, lies beyond an answer that was too high or too low, or
+the part is already solved, is refused without being sent. So is no answer at
+all, when the search gives up. Anything but a correct answer exits with an
+error.
+
+New options:
+  -d, --day <N>       Day to add (1-25)
+      --year <Y>      Year of the puzzle (default: the year in aoc.conf, or 2023)
+      --title <T>     Puzzle title (default: Day N)
+      --root <DIR>    Checkout to add the day to (default: .)
+
+New writes src/days/dayXX.rs with a solution that answers `none`, registers
+it in src/days/mod.rs, and creates empty dXX_full.txt and dXX_simple.txt
+inputs, a dXX_simple.expected sidecar and placeholder lines in answers.txt.
+Files that already exist are kept, except that the day must not exist yet.
+The year goes into the puzzle's link and, unless it already holds one, into
+aoc.conf for fetch and submit; a year other than the one there is refused. To
+start another year, copy this repository without src/days/dayXX.rs and
+aoc.conf, empty the registry and point --root at the copy.
+
+Generate options:
+  -d, --day <N>       Day to generate an input for (1-25)
+      --size <S>      Size of the input (default: 20)
+      --seed <N>      Seed of the input (default: a fresh one)
+
+Generate prints a random input that parses and keeps the puzzle's promises,
+for stress testing and benchmarks. The size scales the input roughly linearly,
+e.g. the number of lines or the side of a grid. Each run makes a new input,
+unless given the seed of an earlier one.
+
+Fuzz options:
+  -d, --day <N>       Day to fuzz (1-25)
+  -a, --all           Fuzz every day
+      --runs <N>      Mutated inputs per day (default: 10000)
+      --timeout <S>   Seconds an input may take before it counts as a hang
+                      (default: 5)
+      --seed <N>      Seed of the mutations and randomized solvers
+                      (default: a fresh one, printed to stderr)
+
+Fuzz mutates the inputs in fuzz/corpus/dXX/ and feeds them to the puzzle,
+which must answer or reject each one with a parse error. It prints
+`day<TAB>runs<TAB>parsed<TAB>result` for every day, stopping a day at its
+first panic or hang. The input that caused it is shrunk, if it panicked, and
+saved under fuzz/artifacts/; any crash exits with an error.
+";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+  Run(RunArgs, parallel::Options, Output),
+  Bench(BenchArgs),
+  Verify(VerifyArgs),
+  Fetch(FetchArgs),
+  Submit(SubmitArgs),
+  New(NewArgs),
+  Generate(GenerateArgs),
+  Fuzz(FuzzArgs),
+  List,
+  Help,
+}
+
+type Params = Vec<(String, String)>;
+
+/// What `run` reports besides the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Output {
+  format: Format,
+  /// Allocations and peak memory of each part.
+  memory: bool,
+}
+
+/// How long `run --all` waits past the timeout for a part that does not check its budget.
+const ABANDON_GRACE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, PartialEq)]
+struct RunArgs {
+  days: Vec<u8>,
+  part: Option<Part>,
+  input: InputSource,
+  params: Params,
+  seed: Option<u64>,
+}
+
+impl RunArgs {
+  fn parts(&self) -> Vec<Part> {
+    match self.part {
+      Some(part) => vec![part],
+      None => Part::BOTH.to_vec(),
+    }
+  }
+
+  /// The given seed, or a fresh one.
+  fn seed(&self) -> u64 {
+    self.seed.unwrap_or_else(random::new_seed)
+  }
+}
+
+#[derive(Debug, PartialEq)]
+struct BenchArgs {
+  run: RunArgs,
+  options: bench::Options,
+  baseline: Option<PathBuf>,
+  save: Option<PathBuf>,
+  /// Regression threshold as a fraction of the baseline median.
+  threshold: f64,
+  timeout: Option<Duration>,
+}
+
+#[derive(Debug, PartialEq)]
+struct VerifyArgs {
+  days: Vec<u8>,
+  answers: PathBuf,
+  timeout: Option<Duration>,
+  seed: Option<u64>,
+  format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+struct FetchArgs {
+  days: Vec<u8>,
+  year: Option<u16>,
+}
+
+#[derive(Debug, PartialEq)]
+struct SubmitArgs {
+  run: RunArgs,
+  part: Part,
+  ledger: PathBuf,
+  year: Option<u16>,
+  timeout: Option<Duration>,
+}
+
+#[derive(Debug, PartialEq)]
+struct NewArgs {
+  year: Option<u16>,
+  day: u8,
+  title: String,
+  root: PathBuf,
+}
+
+#[derive(Debug, PartialEq)]
+struct GenerateArgs {
+  day: u8,
+  size: usize,
+  seed: Option<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+struct FuzzArgs {
+  days: Vec<u8>,
+  options: fuzz::Options,
+  seed: Option<u64>,
+}
+
+/// Removes `-v`, `-vv`, ... and `--verbose` from `args`, returning how many `v`s there were and
+/// the remaining arguments.
+fn take_verbosity(args: impl IntoIterator<Item = String>) -> (u8, Vec<String>) {
+  let mut verbosity = 0u8;
+  let mut rest = Vec::new();
+  for arg in args {
+    let count = match arg.strip_prefix('-') {
+      Some(vs) if !vs.is_empty() && vs.chars().all(|c| c == 'v') => vs.len(),
+      _ if arg == "--verbose" => 1,
+      _ => {
+        rest.push(arg);
+        continue;
+      }
+    };
+    verbosity = verbosity.saturating_add(count.try_into().unwrap_or(u8::MAX));
+  }
+  (verbosity, rest)
+}
+
+/// A cursor over the arguments of a command, which takes a flag's value from after its `=` or
+/// else from the next argument.
+struct Args<I> {
+  args: I,
+  /// The argument [`Args::next_flag`] returned last, as given.
+  current: String,
+  /// Its value after the `=`, if it had one and it was not taken yet.
+  inline_value: Option<String>,
+}
+
+impl<I: Iterator<Item = String>> Args<I> {
+  fn new(args: I) -> Self {
+    Args {
+      args,
+      current: String::new(),
+      inline_value: None,
+    }
+  }
+
+  /// The next argument, without any `=value`, or `None` when there are no more.
+  fn next_flag(&mut self) -> Option<String> {
+    self.current = self.args.next()?;
+    let (flag, inline_value) = match self.current.split_once('=') {
+      Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+      None => (self.current.clone(), None),
+    };
+    self.inline_value = inline_value;
+    Some(flag)
+  }
+
+  /// The value of `flag`, the flag returned last by [`Args::next_flag`].
+  fn value_of(&mut self, flag: &str) -> Result<String, String> {
+    self
+      .inline_value
+      .take()
+      .or_else(|| self.args.next())
+      .ok_or_else(|| format!("Missing value for {}", flag))
+  }
+
+  /// The argument returned last by [`Args::next_flag`] as given, to pass it on to another
+  /// command's parser.
+  fn current(&self) -> String {
+    self.current.clone()
+  }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+  let mut args = args.into_iter();
+  match args.next().as_deref() {
+    Some("run") => parse_run_command(args),
+    Some("bench") => parse_bench_args(args).map(Command::Bench),
+    Some("verify") => parse_verify_args(args).map(Command::Verify),
+    Some("fetch") => parse_fetch_args(args).map(Command::Fetch),
+    Some("submit") => parse_submit_args(args).map(Command::Submit),
+    Some("new") => parse_new_args(args).map(Command::New),
+    Some("generate") => parse_generate_args(args).map(Command::Generate),
+    Some("fuzz") => parse_fuzz_args(args).map(Command::Fuzz),
+    Some("list") => Ok(Command::List),
+    Some("help" | "-h" | "--help") | None => Ok(Command::Help),
+    Some(other) => Err(format!("Unknown command: {}", other)),
+  }
+}
+
+/// Run arguments plus the output and the schedule, of which only the timeout applies to a
+/// single day.
+fn parse_run_command(args: impl Iterator<Item = String>) -> Result<Command, String> {
+  let mut args = Args::new(args);
+  let mut schedule = parallel::Options::default();
+  let mut jobs = false;
+  let mut output = Output::default();
+  let mut run_args = Vec::new();
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "-j" | "--jobs" => {
+        let value = args.value_of(&flag)?;
+        schedule.jobs = value
+          .parse()
+          .ok()
+          .filter(|jobs| *jobs > 0)
+          .ok_or_else(|| format!("Invalid job count: {}", value))?;
+        jobs = true;
+      }
+      "--timeout" => schedule.timeout = parse_timeout(&args.value_of(&flag)?)?,
+      "--format" => output.format = args.value_of(&flag)?.parse()?,
+      "--memory" => output.memory = true,
+      _ => run_args.push(args.current()),
+    }
+  }
+
+  let run = parse_run_args(run_args.into_iter())?;
+  if jobs && run.days.len() == 1 {
+    return Err("--jobs only applies to --all".to_string());
+  }
+  if output.memory {
+    if jobs {
+      return Err("--memory solves one part at a time and takes no --jobs".to_string());
+    }
+    schedule.jobs = 1;
+  }
+  Ok(Command::Run(run, schedule, output))
+}
+
+fn parse_run_args(args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+  let mut args = Args::new(args);
+  let mut day = None;
+  let mut all = false;
+  let mut part = None;
+  let mut input = InputSource::Default;
+  let mut params = Vec::new();
+  let mut seed = None;
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "-d" | "--day" => day = Some(parse_day(&args.value_of(&flag)?)?),
+      "-p" | "--part" => part = Some(args.value_of(&flag)?.parse()?),
+      "-i" | "--input" => input = args.value_of(&flag)?.parse().unwrap(),
+      "-t" | "--text" => input = InputSource::Inline(args.value_of(&flag)?),
+      "-g" | "--generate" => input = InputSource::Generated(parse_size(&args.value_of(&flag)?)?),
+      "--param" => {
+        let param = args.value_of(&flag)?;
+        let (name, value) = param
+          .split_once('=')
+          .ok_or_else(|| format!("Invalid parameter: {} (expected name=value)", param))?;
+        params.push((name.to_string(), value.to_string()));
+      }
+      "--seed" => seed = Some(parse_seed(&args.value_of(&flag)?)?),
+      "-a" | "--all" => all = true,
+      _ => return Err(format!("Unknown option: {}", flag)),
+    }
+  }
+
+  let days = match (day, all) {
+    (Some(_), true) => return Err("--day and --all are mutually exclusive".to_string()),
+    (Some(day), false) => vec![day],
+    (None, true) => days::ALL.iter().map(|day| day.number).collect(),
+    (None, false) => return Err("Specify --day <N> or --all".to_string()),
+  };
+  if days.len() > 1 && !input.is_per_day() {
+    return Err("--all needs a per-day input name, not a file, stdin or text".to_string());
+  }
+
+  Ok(RunArgs {
+    days,
+    part,
+    input,
+    params,
+    seed,
+  })
+}
+
+fn parse_bench_args(args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+  let mut args = Args::new(args);
+  let mut options = bench::Options::default();
+  let mut baseline = None;
+  let mut save = None;
+  let mut threshold = 10.0;
+  let mut timeout = parallel::Options::default().timeout;
+  let mut run_args = Vec::new();
+
+  while let Some(flag) = args.next_flag() {
+    let count = |value: String| {
+      value
+        .parse()
+        .map_err(|_| format!("Invalid count: {}", value))
+    };
+
+    match flag.as_str() {
+      "--runs" => options.runs = count(args.value_of(&flag)?)?,
+      "--warmup" => options.warmup = count(args.value_of(&flag)?)?,
+      "--baseline" => baseline = Some(PathBuf::from(args.value_of(&flag)?)),
+      "--save" => save = Some(PathBuf::from(args.value_of(&flag)?)),
+      "--threshold" => {
+        let value = args.value_of(&flag)?;
+        threshold = value
+          .parse()
+          .ok()
+          .filter(|threshold: &f64| *threshold >= 0.0)
+          .ok_or_else(|| format!("Invalid threshold: {}", value))?;
+      }
+      "--timeout" => timeout = parse_timeout(&args.value_of(&flag)?)?,
+      _ => run_args.push(args.current()),
+    }
+  }
+  if options.runs == 0 {
+    return Err("--runs must be at least 1".to_string());
+  }
+
+  Ok(BenchArgs {
+    run: parse_run_args(run_args.into_iter())?,
+    options,
+    baseline,
+    save,
+    threshold: threshold / 100.0,
+    timeout,
+  })
+}
+
+fn parse_verify_args(args: impl Iterator<Item = String>) -> Result<VerifyArgs, String> {
+  let mut args = Args::new(args);
+  let mut days: Vec<u8> = days::ALL.iter().map(|day| day.number).collect();
+  let mut answers = PathBuf::from(DEFAULT_ANSWERS_FILE);
+  let mut timeout = parallel::Options::default().timeout;
+  let mut seed = None;
+  let mut format = Format::default();
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "-d" | "--day" => days = vec![parse_day(&args.value_of(&flag)?)?],
+      "--answers" => answers = PathBuf::from(args.value_of(&flag)?),
+      "--timeout" => timeout = parse_timeout(&args.value_of(&flag)?)?,
+      "--seed" => seed = Some(parse_seed(&args.value_of(&flag)?)?),
+      "--format" => format = args.value_of(&flag)?.parse()?,
+      _ => return Err(format!("Unknown option: {}", flag)),
+    }
+  }
+
+  Ok(VerifyArgs {
+    days,
+    answers,
+    timeout,
+    seed,
+    format,
+  })
+}
+
+fn parse_fetch_args(args: impl Iterator<Item = String>) -> Result<FetchArgs, String> {
+  let mut args = Args::new(args);
+  let mut day = None;
+  let mut all = false;
+  let mut year = None;
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "-d" | "--day" => day = Some(parse_day(&args.value_of(&flag)?)?),
+      "-a" | "--all" => all = true,
+      "--year" => year = Some(parse_year(&args.value_of(&flag)?)?),
+      _ => return Err(format!("Unknown option: {}", flag)),
+    }
+  }
+
+  let days = match (day, all) {
+    (Some(_), true) => return Err("--day and --all are mutually exclusive".to_string()),
+    (Some(day), false) => vec![day],
+    (None, true) => days::ALL.iter().map(|day| day.number).collect(),
+    (None, false) => return Err("Specify --day <N> or --all".to_string()),
+  };
+  Ok(FetchArgs { days, year })
+}
+
+fn parse_submit_args(args: impl Iterator<Item = String>) -> Result<SubmitArgs, String> {
+  let mut args = Args::new(args);
+  let mut ledger = PathBuf::from(DEFAULT_LEDGER_FILE);
+  let mut year = None;
+  let mut timeout = parallel::Options::default().timeout;
+  let mut run_args = Vec::new();
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "--ledger" => ledger = PathBuf::from(args.value_of(&flag)?),
+      "--year" => year = Some(parse_year(&args.value_of(&flag)?)?),
+      "--timeout" => timeout = parse_timeout(&args.value_of(&flag)?)?,
+      _ => run_args.push(args.current()),
+    }
+  }
+
+  let run = parse_run_args(run_args.into_iter())?;
+  if run.days.len() != 1 {
+    return Err("Submit takes a single --day".to_string());
+  }
+  if let InputSource::Generated(_) = run.input {
+    return Err("Submit needs a puzzle input, not a generated one".to_string());
+  }
+  let part = run.part.ok_or("Specify --part 1 or --part 2")?;
+  Ok(SubmitArgs {
+    run,
+    part,
+    ledger,
+    year,
+    timeout,
+  })
+}
+
+fn parse_new_args(args: impl Iterator<Item = String>) -> Result<NewArgs, String> {
+  let mut args = Args::new(args);
+  let mut year = None;
+  let mut day = None;
+  let mut title = None;
+  let mut root = PathBuf::from(".");
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      // The day need not be registered yet, so it is not checked against `days::ALL`.
+      "-d" | "--day" => {
+        let value = args.value_of(&flag)?;
+        day = Some(
+          value
+            .parse()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| format!("Invalid day: {} (expected 1-25)", value))?,
+        );
+      }
+      "--year" => year = Some(parse_year(&args.value_of(&flag)?)?),
+      "--title" => title = Some(args.value_of(&flag)?),
+      "--root" => root = PathBuf::from(args.value_of(&flag)?),
+      _ => return Err(format!("Unknown option: {}", flag)),
+    }
+  }
+
+  let day = day.ok_or("Specify --day <N>")?;
+  Ok(NewArgs {
+    year,
+    day,
+    title: title.unwrap_or_else(|| format!("Day {}", day)),
+    root,
+  })
+}
+
+fn parse_generate_args(args: impl Iterator<Item = String>) -> Result<GenerateArgs, String> {
+  let mut args = Args::new(args);
+  let mut day = None;
+  let mut size = generate::DEFAULT_SIZE;
+  let mut seed = None;
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "-d" | "--day" => day = Some(parse_day(&args.value_of(&flag)?)?),
+      "--size" => size = parse_size(&args.value_of(&flag)?)?,
+      "--seed" => seed = Some(parse_seed(&args.value_of(&flag)?)?),
+      _ => return Err(format!("Unknown option: {}", flag)),
+    }
+  }
+
+  let day = day.ok_or("Specify --day <N>")?;
+  Ok(GenerateArgs { day, size, seed })
+}
+
+fn parse_fuzz_args(args: impl Iterator<Item = String>) -> Result<FuzzArgs, String> {
+  let mut args = Args::new(args);
+  let mut day = None;
+  let mut all = false;
+  let mut options = fuzz::Options::default();
+  let mut seed = None;
+
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "-d" | "--day" => day = Some(parse_day(&args.value_of(&flag)?)?),
+      "-a" | "--all" => all = true,
+      "--runs" => {
+        let value = args.value_of(&flag)?;
+        options.runs = value
+          .parse()
+          .map_err(|_| format!("Invalid count: {}", value))?;
+      }
+      "--timeout" => {
+        let value = args.value_of(&flag)?;
+        options.timeout = value
+          .parse()
+          .ok()
+          .filter(|seconds: &f64| seconds.is_finite() && *seconds > 0.0)
+          .map(Duration::from_secs_f64)
+          .ok_or_else(|| format!("Invalid timeout: {}", value))?;
+      }
+      "--seed" => seed = Some(parse_seed(&args.value_of(&flag)?)?),
+      _ => return Err(format!("Unknown option: {}", flag)),
+    }
+  }
+
+  let days = match (day, all) {
+    (Some(_), true) => return Err("--day and --all are mutually exclusive".to_string()),
+    (Some(day), false) => vec![day],
+    (None, true) => days::ALL.iter().map(|day| day.number).collect(),
+    (None, false) => return Err("Specify --day <N> or --all".to_string()),
+  };
+  Ok(FuzzArgs {
+    days,
+    options,
+    seed,
+  })
+}
+
+/// Seconds, where 0 means no limit.
+fn parse_timeout(value: &str) -> Result<Option<Duration>, String> {
+  let seconds: f64 = value
+    .parse()
+    .ok()
+    .filter(|seconds: &f64| seconds.is_finite() && *seconds >= 0.0)
+    .ok_or_else(|| format!("Invalid timeout: {}", value))?;
+  Ok((seconds > 0.0).then(|| Duration::from_secs_f64(seconds)))
+}
+
+/// A year with puzzles, which started in 2015.
+fn parse_year(value: &str) -> Result<u16, String> {
+  value
+    .parse()
+    .ok()
+    .filter(|year| *year >= 2015)
+    .ok_or_else(|| format!("Invalid year: {}", value))
+}
+
+fn parse_seed(value: &str) -> Result<u64, String> {
+  value
+    .parse()
+    .map_err(|_| format!("Invalid seed: {}", value))
+}
+
+fn parse_size(value: &str) -> Result<usize, String> {
+  value
+    .parse()
+    .ok()
+    .filter(|size| *size > 0)
+    .ok_or_else(|| format!("Invalid size: {}", value))
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+  value
+    .parse()
+    .ok()
+    .filter(|day| days::get(*day).is_some())
+    .ok_or_else(|| format!("Invalid day: {} (expected 1-25)", value))
+}
+
+/// Loads the input for day `number` with the parameters to set on it, or `None` if the day
+/// should be skipped because a per-day input does not exist.
+fn load_input(args: &RunArgs, number: u8) -> Result<Option<(Input, Params)>, String> {
+  let input = match args.input.load(number) {
+    Ok(input) => input,
+    Err(InputError::Read { path, error })
+      if args.days.len() > 1 && error.kind() == ErrorKind::NotFound =>
+    {
+      eprintln!("skipping day {}: no {}", number, path.display());
+      return Ok(None);
+    }
+    Err(e) => return Err(e.to_string()),
+  };
+
+  let mut params = match &input.path {
+    Some(path) if sidecar_path(path).is_file() => Sidecar::load(&sidecar_path(path))?.params,
+    _ => Vec::new(),
+  };
+  params.extend(args.params.iter().cloned());
+  aoc2023::debug!(
+    "day {}: {} bytes of input from {}, hash {:016x}",
+    number,
+    input.text.len(),
+    input
+      .path
+      .as_ref()
+      .map_or("memory".to_string(), |path| path.display().to_string()),
+    report::input_hash(&input.text)
+  );
+  Ok(Some((input, params)))
+}
+
+fn run(args: &RunArgs, schedule: parallel::Options, output: Output) -> Result<(), String> {
+  let seed = args.seed();
+  random::with_seed(seed, || {
+    if args.days.len() > 1 || output.format == Format::Json || output.memory {
+      aoc2023::debug!("seed {}", seed);
+      return run_all(args, schedule, output, seed);
+    }
+    report_seed(args.seed, seed);
+    run_each(args, schedule.timeout)
+  })
+}
+
+/// Prints `seed` to stderr unless it was `given`, so that a run can be repeated with `--seed`
+/// without asking for `-v` in advance.
+fn report_seed(given: Option<u64>, seed: u64) {
+  match given {
+    None => eprintln!("seed {}", seed),
+    Some(_) => aoc2023::debug!("seed {}", seed),
+  }
+}
+
+/// Solves the days one after the other on this thread, printing each answer as it comes.
+///
+/// Searches that could run forever give up after `timeout`, which prints `gave up` instead of
+/// the answer and fails the command once every part has had its turn.
+fn run_each(args: &RunArgs, timeout: Option<Duration>) -> Result<(), String> {
+  let parts = args.parts();
+  let bare = args.days.len() == 1 && parts.len() == 1;
+  let budget = Budget {
+    time: timeout,
+    steps: None,
+  };
+  let mut gave_up = Vec::new();
+
+  for &number in &args.days {
+    let day = days::get(number).expect("days are validated while parsing arguments");
+    let Some((input, params)) = load_input(args, number)? else {
+      continue;
+    };
+
+    let start = Instant::now();
+    let mut puzzle = day.parse(&input.text).map_err(|e| e.to_string())?;
+    aoc2023::debug!("day {}: parsed in {:.2?}", number, start.elapsed());
+    for (name, value) in &params {
+      puzzle.set_param(name, value)?;
+    }
+    for &part in &parts {
+      let start = Instant::now();
+      let answer = match budget::run(budget, || puzzle.solve(part)) {
+        Ok(answer) => answer.to_string(),
+        Err(e) => {
+          gave_up.push(format!("day {} part {}: {}", number, part, e));
+          "gave up".to_string()
+        }
+      };
+      aoc2023::debug!(
+        "day {} part {}: solved in {:.2?}",
+        number,
+        part,
+        start.elapsed()
+      );
+      if bare {
+        println!("{}", answer);
+      } else {
+        println!("{}\t{}\t{}", number, part, answer);
+      }
+    }
+  }
+
+  if gave_up.is_empty() {
+    Ok(())
+  } else {
+    Err(gave_up.join("\n"))
+  }
+}
+
+/// A part solved, or given up on, by [`solve_timed`].
+struct Solved {
+  answer: Result<Answer, GaveUp>,
+  time: Duration,
+  /// What solving took from the allocator, if it was measured.
+  memory: Option<Usage>,
+}
+
+/// Parses `text` and times solving `part` of it within `budget`, starting from `seed`, and
+/// measures its memory if asked to.
+fn solve_timed(
+  number: u8,
+  part: Part,
+  text: &str,
+  params: &Params,
+  budget: Budget,
+  seed: u64,
+  measure: bool,
+) -> Result<Solved, String> {
+  let day = days::get(number).expect("days are validated while parsing arguments");
+  let start = Instant::now();
+  let mut puzzle = day.parse(text).map_err(|e| e.to_string())?;
+  aoc2023::debug!(
+    "day {} part {}: parsed in {:.2?}",
+    number,
+    part,
+    start.elapsed()
+  );
+  for (name, value) in params {
+    puzzle.set_param(name, value)?;
+  }
+  let solve = || {
+    let start = Instant::now();
+    let answer = random::with_seed(seed, || budget::run(budget, || puzzle.solve(part)));
+    (answer, start.elapsed())
+  };
+  let ((answer, time), memory) = if measure {
+    let (solved, usage) = memory::measure(solve);
+    (solved, Some(usage))
+  } else {
+    (solve(), None)
+  };
+  aoc2023::debug!("day {} part {}: solved in {:.2?}", number, part, time);
+  Ok(Solved {
+    answer,
+    time,
+    memory,
+  })
+}
+
+/// The part of a day whose status is the day's: the worst one, so that a day only passes if
+/// every part was checked.
+fn worst_part(statuses: &[(Part, Status)]) -> &(Part, Status) {
+  let rank = |status: &Status| match status {
+    Status::Error(_) => 0,
+    Status::Fail { .. } => 1,
+    Status::Missing => 2,
+    Status::Pass => 3,
+  };
+  statuses
+    .iter()
+    .min_by_key(|(_, status)| rank(status))
+    .expect("every row has a part")
+}
+
+fn run_all(
+  args: &RunArgs,
+  schedule: parallel::Options,
+  output: Output,
+  seed: u64,
+) -> Result<(), String> {
+  let dir = input::input_dir();
+  let mut known = KnownAnswers::default();
+  if Path::new(DEFAULT_ANSWERS_FILE).is_file() {
+    known = KnownAnswers::load(Path::new(DEFAULT_ANSWERS_FILE))?;
+  }
+  known.load_sidecars(&dir)?;
+  let input_name = match &args.input {
+    InputSource::Default => "full".to_string(),
+    InputSource::Path(name) => name.display().to_string(),
+    InputSource::Stdin => "stdin".to_string(),
+    InputSource::Inline(_) => "text".to_string(),
+    InputSource::Generated(_) => "generated".to_string(),
+  };
+
+  let mut jobs = Vec::new();
+  for &number in &args.days {
+    let Some((input, params)) = load_input(args, number)? else {
+      continue;
+    };
+    let hash = report::input_hash(&input.text);
+    let (text, params) = (Arc::new(input.text), Arc::new(params));
+    for part in args.parts() {
+      jobs.push((number, part, hash, Arc::clone(&text), Arc::clone(&params)));
+    }
+  }
+  let keys: Vec<(u8, Part, u64)> = jobs
+    .iter()
+    .map(|&(number, part, hash, _, _)| (number, part, hash))
+    .collect();
+
+  // Panics are reported in the table. The hook stays silent afterwards, since abandoned parts
+  // may still panic in the background.
+  panic::set_hook(Box::new(|_| {}));
+  // Parts that check their budget give up at the timeout; the rest are abandoned a little later.
+  let budget = Budget {
+    time: schedule.timeout,
+    steps: None,
+  };
+  let backstop = parallel::Options {
+    timeout: schedule.timeout.map(|timeout| timeout + ABANDON_GRACE),
+    ..schedule
+  };
+  let results = parallel::map(jobs, backstop, move |(number, part, _, text, params)| {
+    solve_timed(number, part, &text, &params, budget, seed, output.memory)
+  });
+
+  let mut table = vec!["day\tpart1\ttime\tpart2\ttime\tstatus".to_string()];
+  let mut checks = Vec::new();
+  let (mut passed, mut failed, mut missing) = (0, 0, 0);
+  let mut rows = keys.into_iter().zip(results).peekable();
+  while let Some(&((number, _, _), _)) = rows.peek() {
+    let mut cells = Vec::new();
+    let mut statuses = Vec::new();
+    for part in Part::BOTH {
+      let Some(((_, _, hash), result)) = rows.next_if(|&((n, p, _), _)| n == number && p == part)
+      else {
+        cells.push("-\t-".to_string());
+        continue;
+      };
+      let expected = known.get(number, part, &input_name);
+      let memory = match &result {
+        Ok(Ok(solved)) => solved.memory,
+        _ => None,
+      };
+      let (cell, answer, time, status) = match result {
+        Ok(Ok(Solved {
+          answer: Ok(answer),
+          time,
+          ..
+        })) => {
+          let status = Status::of(expected, &answer);
+          let cell = format!("{}\t{:.2?}", answer, time);
+          (cell, Some(answer), Some(time), status)
+        }
+        Ok(Ok(Solved {
+          answer: Err(gave_up),
+          ..
+        })) => {
+          let status = Status::Error(gave_up.to_string());
+          ("gave up\t-".to_string(), None, None, status)
+        }
+        Ok(Err(e)) => ("error\t-".to_string(), None, None, Status::Error(e)),
+        Err(Failure::Panic(e)) => ("panic\t-".to_string(), None, None, Status::Error(e)),
+        Err(Failure::Timeout) => {
+          let timeout = schedule.timeout.expect("only a timeout abandons a part");
+          let e = format!("timed out after {:?}", timeout);
+          ("timeout\t-".to_string(), None, None, Status::Error(e))
+        }
+      };
+      cells.push(cell);
+      statuses.push((part, status.clone()));
+      checks.push(Check {
+        day: number,
+        part,
+        input: input_name.clone(),
+        hash: Some(hash),
+        seed: Some(seed),
+        answer,
+        time,
+        memory,
+        status,
+      });
+    }
+
+    let (part, status) = worst_part(&statuses);
+    let detail = match status {
+      Status::Fail { expected, .. } => format!("\tpart {} expected {}", part, expected),
+      Status::Error(e) => format!("\tpart {}: {}", part, e),
+      Status::Pass | Status::Missing => String::new(),
+    };
+    match status {
+      Status::Pass => passed += 1,
+      Status::Missing => missing += 1,
+      Status::Fail { .. } | Status::Error(_) => failed += 1,
+    }
+    table.push(format!(
+      "{}\t{}\t{}{}",
+      number,
+      cells.join("\t"),
+      status,
+      detail
+    ));
+  }
+
+  match output.format {
+    Format::Text => {
+      for row in table {
+        println!("{}", row);
+      }
+      if output.memory {
+        println!("\nday\tpart\tallocs\tbytes\tpeak\tprocess rss");
+        for check in &checks {
+          let cells = match check.memory {
+            Some(usage) => format!(
+              "{}\t{}\t{}\t{}",
+              usage.allocations,
+              Bytes(usage.bytes),
+              Bytes(usage.peak),
+              usage
+                .rss
+                .map_or("-".to_string(), |rss| Bytes(rss).to_string())
+            ),
+            None => "-\t-\t-\t-".to_string(),
+          };
+          println!("{}\t{}\t{}", check.day, check.part, cells);
+        }
+      }
+      println!(
+        "\n{} passed, {} failed, {} missing, seed {}",
+        passed, failed, missing, seed
+      );
+    }
+    Format::Json => print!("{}", report::to_json(&checks)),
+  }
+  if failed > 0 {
+    Err(format!("{} day(s) failed", failed))
+  } else {
+    Ok(())
+  }
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+  let seed = args.run.seed();
+  report_seed(args.run.seed, seed);
+  random::with_seed(seed, || bench_days(args))
+}
+
+fn bench_days(args: &BenchArgs) -> Result<(), String> {
+  let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+  let mut medians = match &args.save {
+    Some(path) if path.is_file() => Baseline::load(path)?,
+    _ => Baseline::default(),
+  };
+  let mut regressions = 0;
+
+  print!("day\tstage\tmedian\tmin\tmax");
+  if baseline.is_some() {
+    print!("\tbaseline\tchange");
+  }
+  println!();
+
+  for &number in &args.run.days {
+    let day = days::get(number).expect("days are validated while parsing arguments");
+    let Some((input, params)) = load_input(&args.run, number)? else {
+      continue;
+    };
+
+    let budget = Budget {
+      time: args.timeout,
+      steps: None,
+    };
+    let parts = args.run.parts();
+    let timings = bench::bench_day(day, &input.text, &params, &parts, args.options, budget)
+      .map_err(|e| format!("day {}: {}", number, e))?;
+    for (stage, timing) in timings {
+      medians.insert(number, stage, timing.median);
+      print!(
+        "{}\t{}\t{:.2?}\t{:.2?}\t{:.2?}",
+        number, stage, timing.median, timing.min, timing.max
+      );
+      if let Some(previous) = baseline.as_ref().and_then(|b| b.get(number, stage)) {
+        let change = timing.median.as_secs_f64() / previous.as_secs_f64() - 1.0;
+        print!("\t{:.2?}\t{:+.1}%", previous, change * 100.0);
+        if bench::is_regression(timing.median, previous, args.threshold) {
+          print!("\tREGRESSION");
+          regressions += 1;
+        }
+      }
+      println!();
+    }
+  }
+
+  if let Some(path) = &args.save {
+    medians.save(path)?;
+    eprintln!("saved baseline to {}", path.display());
+  }
+  if regressions > 0 {
+    return Err(format!("{} stage(s) regressed", regressions));
+  }
+  Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+  let dir = input::input_dir();
+  let mut known = KnownAnswers::load(&args.answers)?;
+  known.load_sidecars(&dir)?;
+
+  // Panics are reported per check, so keep the default hook from printing them as well.
+  panic::set_hook(Box::new(|_| {}));
+  let budget = Budget {
+    time: args.timeout,
+    steps: None,
+  };
+  let seed = args.seed.unwrap_or_else(random::new_seed);
+  let checks = random::with_seed(seed, || verify::verify(&known, &args.days, &dir, budget));
+  let _ = panic::take_hook();
+
+  let count = |f: fn(&Status) -> bool| checks.iter().filter(|check| f(&check.status)).count();
+  let failed = count(|status| matches!(status, Status::Fail { .. } | Status::Error(_)));
+  match args.format {
+    Format::Text => {
+      for check in &checks {
+        let detail = match &check.status {
+          Status::Fail { expected, actual } => format!("\texpected {}, got {}", expected, actual),
+          Status::Error(e) => format!("\t{}", e),
+          Status::Pass | Status::Missing => String::new(),
+        };
+        println!(
+          "{}\t{}\t{}\t{}{}",
+          check.day, check.part, check.input, check.status, detail
+        );
+      }
+      println!(
+        "\n{} passed, {} failed, {} missing, seed {}",
+        count(|status| *status == Status::Pass),
+        failed,
+        count(|status| *status == Status::Missing),
+        seed
+      );
+    }
+    Format::Json => print!("{}", report::to_json(&checks)),
+  }
+
+  if failed > 0 {
+    Err(format!("{} check(s) failed", failed))
+  } else {
+    Ok(())
+  }
+}
+
+/// A client for `year`, or else for the year in the [`remote::CONFIG_FILE`] of the current
+/// directory, or else for [`remote::YEAR`].
+fn client(year: Option<u16>) -> Result<Client, String> {
+  let year = match year {
+    Some(year) => year,
+    None => remote::load_year(Path::new(remote::CONFIG_FILE))?.unwrap_or(remote::YEAR),
+  };
+  Client::from_env(year)
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), String> {
+  let client = client(args.year)?;
+  let dir = input::input_dir();
+  for &number in &args.days {
+    let (path, fetch) = remote::fetch_input(&client, &dir, number)?;
+    let status = match fetch {
+      Fetch::Cached => "cached",
+      Fetch::Downloaded => "downloaded",
+    };
+    println!("{}\t{}\t{}", number, status, path.display());
+  }
+  Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), String> {
+  let number = args.run.days[0];
+  let day = days::get(number).expect("days are validated while parsing arguments");
+  let (input, params) = load_input(&args.run, number)?.expect("a single day is never skipped");
+  let mut puzzle = day.parse(&input.text).map_err(|e| e.to_string())?;
+  for (name, value) in &params {
+    puzzle.set_param(name, value)?;
+  }
+  let seed = args.run.seed();
+  report_seed(args.run.seed, seed);
+  let budget = Budget {
+    time: args.timeout,
+    steps: None,
+  };
+  let answer = random::with_seed(seed, || budget::run(budget, || puzzle.solve(args.part)))
+    .map_err(|e| format!("Not submitting day {} part {}: {}", number, args.part, e))?;
+
+  let mut ledger = if args.ledger.is_file() {
+    Ledger::load(&args.ledger)?
+  } else {
+    Ledger::default()
+  };
+  if let Some(reason) = ledger.refusal(number, args.part, &answer) {
+    return Err(format!("Not submitting {}: {}", answer, reason));
+  }
+
+  let verdict = client(args.year)?.submit(number, args.part, &answer)?;
+  println!("{}\t{}\t{}\t{}", number, args.part, answer, verdict);
+  if ledger.record(number, args.part, verdict.clone(), answer) {
+    ledger.save(&args.ledger)?;
+  }
+
+  match verdict {
+    Verdict::Correct => Ok(()),
+    Verdict::RateLimited(Some(wait)) => Err(format!("Rate limited, try again in {}", wait)),
+    Verdict::RateLimited(None) => Err("Rate limited, try again later".to_string()),
+    Verdict::AlreadySolved => Err("The part is already solved on the website".to_string()),
+    Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => {
+      Err(format!("The answer was rejected as {}", verdict))
+    }
+  }
+}
+
+fn new(args: &NewArgs) -> Result<(), String> {
+  let changed = scaffold::new_day(&args.root, args.year, args.day, &args.title)?;
+  for path in changed {
+    println!("{}", path.display());
+  }
+  Ok(())
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), String> {
+  let seed = args.seed.unwrap_or_else(random::new_seed);
+  report_seed(args.seed, seed);
+  let input = random::with_seed(seed, || InputSource::Generated(args.size).load(args.day))
+    .map_err(|e| e.to_string())?;
+  print!("{}", input.text);
+  Ok(())
+}
+
+fn fuzz(args: &FuzzArgs) -> Result<(), String> {
+  // Crashes are reported per day, so keep the default hook from printing every panic.
+  panic::set_hook(Box::new(|_| {}));
+  let seed = args.seed.unwrap_or_else(random::new_seed);
+  eprintln!("fuzzing with seed {}", seed);
+  let mut crashed = 0;
+  for &number in &args.days {
+    let day = days::get(number).expect("days are validated while parsing arguments");
+    let corpus = fuzz::load_corpus(Path::new(fuzz::CORPUS_DIR), number)?;
+    let report = random::with_seed(seed, || {
+      fuzz::fuzz(*day, &corpus, args.options, &mut random::rng())
+    });
+
+    let result = match &report.crash {
+      None => "ok".to_string(),
+      Some(crash) => {
+        crashed += 1;
+        let path = fuzz::save_artifact(Path::new(fuzz::ARTIFACT_DIR), number, &crash.input)?;
+        match &crash.failure {
+          Failure::Panic(message) => format!("panic\t{}\t{}", path.display(), message),
+          Failure::Timeout => format!("hang\t{}", path.display()),
+        }
+      }
+    };
+    println!("{}\t{}\t{}\t{}", number, report.runs, report.parsed, result);
+  }
+
+  if crashed > 0 {
+    Err(format!("{} day(s) crashed", crashed))
+  } else {
+    Ok(())
+  }
+}
+
+fn main() -> ExitCode {
+  let (verbosity, args) = take_verbosity(env::args().skip(1));
+  log::set_verbosity(verbosity);
+  let result = match parse_args(args) {
+    Ok(Command::Run(args, schedule, output)) => run(&args, schedule, output),
+    Ok(Command::Bench(args)) => bench(&args),
+    Ok(Command::Verify(args)) => verify(&args),
+    Ok(Command::Fetch(args)) => fetch(&args),
+    Ok(Command::Submit(args)) => submit(&args),
+    Ok(Command::New(args)) => new(&args),
+    Ok(Command::Generate(args)) => generate(&args),
+    Ok(Command::Fuzz(args)) => fuzz(&args),
+    Ok(Command::List) => {
+      for day in &days::ALL {
+        println!("{}\t{}", day.number, day.title);
+      }
+      Ok(())
+    }
+    Ok(Command::Help) => {
+      print!("{}", USAGE);
+      Ok(())
+    }
+    Err(e) => Err(format!("{}\n\n{}", e, USAGE)),
+  };
+
+  match result {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(e) => {
+      eprintln!("error: {}", e);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(line: &str) -> Result<Command, String> {
+    parse_args(line.split_whitespace().map(String::from))
+  }
+
+  #[test]
+  fn test_parse_run_single_part() {
+    assert_eq!(
+      parse("run --day 17 --part 2 --input path.txt"),
+      Ok(Command::Run(
+        RunArgs {
+          days: vec![17],
+          part: Some(Part::Two),
+          input: InputSource::Path("path.txt".into()),
+          params: Vec::new(),
+          seed: None,
+        },
+        parallel::Options::default(),
+        Output::default()
+      ))
+    );
+  }
+
+  #[test]
+  fn test_parse_run_all() {
+    let Ok(Command::Run(args, _, _)) = parse("run --all -p=1") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(args.days, (1..=25).collect::<Vec<_>>());
+    assert_eq!(args.part, Some(Part::One));
+  }
+
+  #[test]
+  fn test_parse_run_all_schedule() {
+    let Ok(Command::Run(_, schedule, _)) = parse("run --all -j 3 --timeout=2.5") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(schedule.jobs, 3);
+    assert_eq!(schedule.timeout, Some(Duration::from_millis(2500)));
+
+    let Ok(Command::Run(_, schedule, _)) = parse("run --all --timeout 0") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(schedule.timeout, None);
+    assert!(parse("run --all --jobs 0").is_err());
+    assert!(parse("run --all --timeout -1").is_err());
+    assert!(parse("run --day 3 --jobs 2").is_err());
+
+    let Ok(Command::Run(_, schedule, _)) = parse("run --day 24 --timeout 5") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(schedule.timeout, Some(Duration::from_secs(5)));
+  }
+
+  #[test]
+  fn test_worst_part() {
+    let fail = Status::Fail {
+      expected: Answer::from(1),
+      actual: Answer::from(2),
+    };
+    let worst = |one: &Status, two: &Status| {
+      worst_part(&[(Part::One, one.clone()), (Part::Two, two.clone())]).clone()
+    };
+    assert_eq!(
+      worst(&Status::Pass, &Status::Pass),
+      (Part::One, Status::Pass)
+    );
+    assert_eq!(
+      worst(&Status::Pass, &Status::Missing),
+      (Part::Two, Status::Missing)
+    );
+    assert_eq!(worst(&Status::Missing, &fail), (Part::Two, fail.clone()));
+    let error = Status::Error("boom".to_string());
+    assert_eq!(worst(&fail, &error), (Part::Two, error));
+  }
+
+  #[test]
+  fn test_args_cursor() {
+    let mut args = Args::new("--day=3 --part 2 --all".split(' ').map(String::from));
+    assert_eq!(args.next_flag().as_deref(), Some("--day"));
+    assert_eq!(args.current(), "--day=3");
+    assert_eq!(args.value_of("--day"), Ok("3".to_string()));
+    assert_eq!(args.next_flag().as_deref(), Some("--part"));
+    assert_eq!(args.value_of("--part"), Ok("2".to_string()));
+    assert_eq!(args.next_flag().as_deref(), Some("--all"));
+    assert!(args.value_of("--all").is_err());
+    assert_eq!(args.next_flag(), None);
+  }
+
+  #[test]
+  fn test_take_verbosity() {
+    let split = |line: &str| take_verbosity(line.split_whitespace().map(String::from));
+    assert_eq!(
+      split("run -d 3"),
+      (0, vec!["run".into(), "-d".into(), "3".into()])
+    );
+    assert_eq!(
+      split("-v run --verbose -d 3"),
+      (2, vec!["run".into(), "-d".into(), "3".into()])
+    );
+    assert_eq!(split("run -vv"), (2, vec!["run".into()]));
+    assert_eq!(split("run -v -vvv").0, 4);
+    assert_eq!(split("run - --").1, vec!["run", "-", "--"]);
+  }
+
+  #[test]
+  fn test_parse_format() {
+    let Ok(Command::Run(_, _, output)) = parse("run --all --format json") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(output.format, Format::Json);
+    assert!(matches!(
+      parse("run -d 3 --format=json"),
+      Ok(Command::Run(
+        _,
+        _,
+        Output {
+          format: Format::Json,
+          ..
+        }
+      ))
+    ));
+    assert!(matches!(
+      parse("verify --format json"),
+      Ok(Command::Verify(VerifyArgs {
+        format: Format::Json,
+        ..
+      }))
+    ));
+    assert!(parse("run -d 3 --format xml").is_err());
+    assert!(parse("bench -d 3 --format json").is_err());
+  }
+
+  #[test]
+  fn test_parse_memory() {
+    let Ok(Command::Run(_, schedule, output)) = parse("run --all --memory --timeout 5") else {
+      panic!("expected a run command");
+    };
+    assert!(output.memory);
+    assert_eq!(schedule.jobs, 1);
+    assert!(matches!(
+      parse("run -d 14 --memory"),
+      Ok(Command::Run(_, _, Output { memory: true, .. }))
+    ));
+    assert!(parse("run --all --memory -j 2").is_err());
+    assert!(parse("bench -d 14 --memory").is_err());
+  }
+
+  #[test]
+  fn test_parse_run_params() {
+    let Ok(Command::Run(args, _, _)) = parse("run -d 21 --param steps=6 --param=x=1") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(
+      args.params,
+      vec![
+        ("steps".to_string(), "6".to_string()),
+        ("x".to_string(), "1".to_string())
+      ]
+    );
+    assert!(parse("run -d 21 --param steps").is_err());
+  }
+
+  #[test]
+  fn test_parse_bench() {
+    let Ok(Command::Bench(args)) =
+      parse("bench --day 23 --runs 3 --warmup=0 --part 2 --baseline base.txt --threshold 25")
+    else {
+      panic!("expected a bench command");
+    };
+    assert_eq!(args.run.days, vec![23]);
+    assert_eq!(args.run.part, Some(Part::Two));
+    assert_eq!(args.options, bench::Options { warmup: 0, runs: 3 });
+    assert_eq!(args.baseline, Some(PathBuf::from("base.txt")));
+    assert_eq!(args.save, None);
+    assert_eq!(args.threshold, 0.25);
+
+    assert!(parse("bench --all --runs 0").is_err());
+    assert!(parse("bench --runs 3").is_err());
+  }
+
+  #[test]
+  fn test_parse_verify() {
+    assert_eq!(
+      parse("verify --day 8 --answers other.txt"),
+      Ok(Command::Verify(VerifyArgs {
+        days: vec![8],
+        answers: PathBuf::from("other.txt"),
+        timeout: Some(Duration::from_secs(60)),
+        seed: None,
+        format: Format::Text,
+      }))
+    );
+    let Ok(Command::Verify(args)) = parse("verify") else {
+      panic!("expected a verify command");
+    };
+    assert_eq!(args.days.len(), 25);
+    assert_eq!(args.answers, PathBuf::from(DEFAULT_ANSWERS_FILE));
+    assert!(parse("verify --day 0").is_err());
+
+    let Ok(Command::Verify(args)) = parse("verify --timeout 0") else {
+      panic!("expected a verify command");
+    };
+    assert_eq!(args.timeout, None);
+    assert!(parse("verify --timeout x").is_err());
+  }
+
+  #[test]
+  fn test_parse_fetch() {
+    assert_eq!(
+      parse("fetch --day 5"),
+      Ok(Command::Fetch(FetchArgs {
+        days: vec![5],
+        year: None
+      }))
+    );
+    let Ok(Command::Fetch(args)) = parse("fetch -d 5 --year 2022") else {
+      panic!("expected a fetch command");
+    };
+    assert_eq!(args.year, Some(2022));
+    let Ok(Command::Fetch(args)) = parse("fetch -a") else {
+      panic!("expected a fetch command");
+    };
+    assert_eq!(args.days.len(), 25);
+    assert!(parse("fetch").is_err());
+    assert!(parse("fetch --day 5 --all").is_err());
+    assert!(parse("fetch --day 5 --input x").is_err());
+  }
+
+  #[test]
+  fn test_parse_submit() {
+    let Ok(Command::Submit(args)) = parse("submit --day 7 --part 2 --ledger=l.txt") else {
+      panic!("expected a submit command");
+    };
+    assert_eq!(args.run.days, vec![7]);
+    assert_eq!(args.part, Part::Two);
+    assert_eq!(args.ledger, PathBuf::from("l.txt"));
+    assert_eq!(args.year, None);
+    assert_eq!(args.timeout, Some(Duration::from_secs(60)));
+
+    let Ok(Command::Submit(args)) = parse("submit -d 7 -p 1") else {
+      panic!("expected a submit command");
+    };
+    assert_eq!(args.ledger, PathBuf::from(DEFAULT_LEDGER_FILE));
+    let Ok(Command::Submit(args)) = parse("submit -d 25 -p 1 --timeout=0 --year 2024") else {
+      panic!("expected a submit command");
+    };
+    assert_eq!(args.timeout, None);
+    assert_eq!(args.year, Some(2024));
+    assert!(parse("submit --day 7").is_err());
+    assert!(parse("submit --all --part 1").is_err());
+    assert!(parse("submit --day 7 --part 1 --ledger").is_err());
+  }
+
+  #[test]
+  fn test_parse_new() {
+    assert_eq!(
+      parse("new --year 2024 --day 3 --root ../aoc2024"),
+      Ok(Command::New(NewArgs {
+        year: Some(2024),
+        day: 3,
+        title: "Day 3".to_string(),
+        root: PathBuf::from("../aoc2024"),
+      }))
+    );
+    let Ok(Command::New(args)) = parse("new -d 25 --title=Snowverload") else {
+      panic!("expected a new command");
+    };
+    assert_eq!(args.year, None);
+    assert_eq!(args.title, "Snowverload");
+    assert_eq!(args.root, PathBuf::from("."));
+    assert!(parse("new").is_err());
+    assert!(parse("new --day 26").is_err());
+    assert!(parse("new --day 1 --year 1999").is_err());
+  }
+
+  #[test]
+  fn test_parse_generate() {
+    assert_eq!(
+      parse("generate --day 10 --size=50"),
+      Ok(Command::Generate(GenerateArgs {
+        day: 10,
+        size: 50,
+        seed: None
+      }))
+    );
+    let Ok(Command::Generate(args)) = parse("generate -d 3") else {
+      panic!("expected a generate command");
+    };
+    assert_eq!(args.size, generate::DEFAULT_SIZE);
+    assert!(parse("generate").is_err());
+    assert!(parse("generate -d 3 --size 0").is_err());
+
+    let Ok(Command::Run(args, _, _)) = parse("run --all -g 5") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(args.input, InputSource::Generated(5));
+    assert!(parse("submit -d 3 -p 1 -g 5").is_err());
+  }
+
+  #[test]
+  fn test_parse_seed() {
+    let Ok(Command::Run(args, _, _)) = parse("run --all --seed 42") else {
+      panic!("expected a run command");
+    };
+    assert_eq!(args.seed, Some(42));
+    let Ok(Command::Bench(args)) = parse("bench -d 25 --seed=7") else {
+      panic!("expected a bench command");
+    };
+    assert_eq!(args.run.seed, Some(7));
+    assert!(matches!(
+      parse("verify --seed 1"),
+      Ok(Command::Verify(VerifyArgs { seed: Some(1), .. }))
+    ));
+    assert!(matches!(
+      parse("generate -d 3 --seed 1"),
+      Ok(Command::Generate(GenerateArgs { seed: Some(1), .. }))
+    ));
+    assert!(matches!(
+      parse("fuzz -d 3 --seed 1"),
+      Ok(Command::Fuzz(FuzzArgs { seed: Some(1), .. }))
+    ));
+    assert!(parse("run -d 25 --seed -1").is_err());
+    assert!(parse("run -d 25 --seed x").is_err());
+  }
+
+  #[test]
+  fn test_parse_rejects_bad_arguments() {
+    assert!(parse("run").is_err());
+    assert!(parse("run --day 26").is_err());
+    assert!(parse("run --day 3 --part 3").is_err());
+    assert!(parse("run --all --input x.txt").is_err());
+    assert!(parse("run --all --input -").is_err());
+    assert!(parse("run --all --input simple").is_ok());
+    assert!(parse("run --day 3 --all").is_err());
+    assert!(parse("frobnicate").is_err());
+  }
 }
@@ -0,0 +1,141 @@
+use std::fmt::{self, Display};
+
+/// A result too large for a `u128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "overflows u128")
+  }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Greatest common divisor; `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+  a
+}
+
+/// Least common multiple, or [`Overflow`] if it does not fit in a `u128`. It is 0 if either
+/// is 0.
+pub fn lcm(a: u128, b: u128) -> Result<u128, Overflow> {
+  if a == 0 || b == 0 {
+    return Ok(0);
+  }
+  (a / gcd(a, b)).checked_mul(b).ok_or(Overflow)
+}
+
+/// Least common multiple of all of `numbers`, 1 if there are none, or [`Overflow`].
+pub fn lcm_all(numbers: impl IntoIterator<Item = u128>) -> Result<u128, Overflow> {
+  numbers.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, where `g` is the non-negative gcd of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+  let (mut old_r, mut r) = (a, b);
+  let (mut old_x, mut x) = (1, 0);
+  let (mut old_y, mut y) = (0, 1);
+
+  while r != 0 {
+    let quotient = old_r / r;
+    (old_r, r) = (r, old_r - quotient * r);
+    (old_x, x) = (x, old_x - quotient * x);
+    (old_y, y) = (y, old_y - quotient * y);
+  }
+
+  if old_r < 0 {
+    (-old_r, -old_x, -old_y)
+  } else {
+    (old_r, old_x, old_y)
+  }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or `None` if `a` and `modulus` are
+/// not coprime or `modulus` is not positive.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+  if modulus <= 0 {
+    return None;
+  }
+  let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+  (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, whose
+/// moduli need not be coprime.
+///
+/// Returns the smallest non-negative solution together with the lcm of the moduli, as every
+/// solution is that plus a multiple of the lcm. `None` if the congruences contradict each other,
+/// a modulus is not positive, or the lcm overflows an `i128`.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+  let mut solution = (0, 1);
+
+  for &(residue, modulus) in congruences {
+    if modulus <= 0 {
+      return None;
+    }
+    let (r1, m1) = solution;
+    let r2 = residue.rem_euclid(modulus);
+
+    // x = r1 + m1 * k, so m1 * k ≡ r2 - r1 (mod modulus), solvable when g divides the difference.
+    let (g, inverse, _) = extended_gcd(m1, modulus);
+    let difference = r2 - r1;
+    if difference % g != 0 {
+      return None;
+    }
+    let step = modulus / g;
+    let k = (difference / g)
+      .rem_euclid(step)
+      .checked_mul(inverse.rem_euclid(step))?
+      .rem_euclid(step);
+
+    let combined = m1.checked_mul(step)?;
+    solution = (
+      m1.checked_mul(k)?.checked_add(r1)?.rem_euclid(combined),
+      combined,
+    );
+  }
+
+  Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_gcd_and_lcm() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(lcm(4, 6), Ok(12));
+    assert_eq!(lcm_all([2, 3, 4, 5]), Ok(60));
+    assert_eq!(lcm_all([]), Ok(1));
+    assert_eq!(lcm(u128::MAX, u128::MAX - 1), Err(Overflow));
+    assert_eq!(lcm_all([u128::MAX, 0, u128::MAX - 1]), Ok(0));
+  }
+
+  #[test]
+  fn test_extended_gcd_and_inverse() {
+    let (g, x, y) = extended_gcd(240, 46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+    assert_eq!(extended_gcd(-4, 6).0, 2);
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(4, 8), None);
+  }
+
+  #[test]
+  fn test_crt() {
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    // Non-coprime moduli: x ≡ 3 (mod 4) and x ≡ 5 (mod 6) gives x ≡ 11 (mod 12).
+    assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+    assert_eq!(crt(&[]), Some((0, 1)));
+    assert_eq!(crt(&[(0, i128::MAX), (1, i128::MAX - 1)]), None);
+  }
+}
@@ -0,0 +1,262 @@
+//! Allocation counts and peak memory of solving a part, for finding the days that allocate the
+//! most.
+//!
+//! [`Counting`] is a global allocator that passes every request on to the system allocator and
+//! counts it if the thread is inside [`measure`]; elsewhere a request costs one thread-local
+//! read more. The `aoc` binary installs it, so `run --memory` can report what each part did.
+//! The counts are per thread, so parts solved side by side do not mix. The peak resident set
+//! size is sampled from `/proc/self/status`, which only Linux has, and covers the whole process.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Display};
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Time between two readings of the resident set size.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(1);
+
+/// The system allocator, counting the requests made inside [`measure`].
+pub struct Counting;
+
+// SAFETY: Every method hands its arguments unchanged to the same method of `System` and returns
+// its result, so each block comes from `System` and goes back to it with the layout it was made
+// with, and `System` upholds the `GlobalAlloc` contract. All that is added is a call to `count`,
+// which can neither allocate, and so re-enter the allocator, nor unwind out of it.
+unsafe impl GlobalAlloc for Counting {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    count(layout.size(), 0);
+    // SAFETY: The caller meets `alloc`'s requirements for `layout`, which are all `System` needs.
+    unsafe { System.alloc(layout) }
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    count(layout.size(), 0);
+    // SAFETY: As for `alloc`.
+    unsafe { System.alloc_zeroed(layout) }
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    count(0, layout.size());
+    // SAFETY: The caller passes a block this allocator returned for `layout`, and this allocator
+    // returns `System`'s blocks unchanged.
+    unsafe { System.dealloc(ptr, layout) }
+  }
+
+  /// Counts as one allocation of the new size and a release of the old one, which is what it
+  /// costs when the block has to move.
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    count(new_size, layout.size());
+    // SAFETY: `ptr` is a `System` block of `layout`, as for `dealloc`, and the caller meets the
+    // requirements on `new_size`.
+    unsafe { System.realloc(ptr, layout, new_size) }
+  }
+}
+
+/// What solving a part took from the allocator, and from the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+  /// Allocations and reallocations.
+  pub allocations: u64,
+  /// Bytes requested by all of them together.
+  pub bytes: u64,
+  /// Most bytes held at once beyond what was held at the start.
+  pub peak: u64,
+  /// Highest resident set size of the process seen meanwhile, if the system reports it.
+  pub rss: Option<u64>,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+  on: bool,
+  allocations: u64,
+  bytes: u64,
+  /// Bytes held now less those held at the start; negative after freeing older blocks.
+  live: i64,
+  peak: i64,
+}
+
+const OFF: Counters = Counters {
+  on: false,
+  allocations: 0,
+  bytes: 0,
+  live: 0,
+  peak: 0,
+};
+
+thread_local! {
+  // Constant and without a destructor, so the allocator can use it at any point of a thread's
+  // life without allocating.
+  static COUNTERS: Cell<Counters> = const { Cell::new(OFF) };
+}
+
+/// Adds a request to the counters of this thread, if it is inside [`measure`].
+///
+/// Called by the allocator, so it must neither allocate nor panic: it only copies the counters
+/// in and out of their thread-local, which cannot fail for a constant without a destructor, and
+/// its sums saturate rather than overflow.
+fn count(allocated: usize, freed: usize) {
+  let mut counters = COUNTERS.get();
+  if !counters.on {
+    return;
+  }
+  if allocated > 0 {
+    counters.allocations = counters.allocations.saturating_add(1);
+    counters.bytes = counters.bytes.saturating_add(allocated as u64);
+  }
+  counters.live = counters
+    .live
+    .saturating_add(allocated as i64)
+    .saturating_sub(freed as i64);
+  counters.peak = counters.peak.max(counters.live);
+  COUNTERS.set(counters);
+}
+
+/// Runs `f`, counting what it allocates on this thread and sampling the resident set size.
+///
+/// The counts only come from [`Counting`] when it is the global allocator; otherwise they stay
+/// at zero. An inner `measure` adds its counts to the outer one.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+  // Counting pauses while the sampler starts and stops, which allocates.
+  let mut resume = Resume {
+    outer: COUNTERS.replace(OFF),
+    inner: OFF,
+  };
+  let sampling = AtomicBool::new(true);
+  let (result, rss) = thread::scope(|scope| {
+    let sampler = scope.spawn(|| {
+      let mut peak = resident_set_size();
+      while sampling.load(Ordering::Relaxed) {
+        thread::sleep(SAMPLE_INTERVAL);
+        peak = peak.max(resident_set_size());
+      }
+      peak
+    });
+    let stop = Stop(&sampling);
+
+    COUNTERS.set(Counters { on: true, ..OFF });
+    let result = f();
+    resume.inner = COUNTERS.replace(OFF);
+
+    drop(stop);
+    (result, sampler.join().expect("the sampler does not panic"))
+  });
+
+  let inner = resume.inner;
+  let usage = Usage {
+    allocations: inner.allocations,
+    bytes: inner.bytes,
+    peak: inner.peak as u64,
+    rss,
+  };
+  (result, usage)
+}
+
+/// Puts back the counters of an enclosing [`measure`], adding what the inner one counted.
+struct Resume {
+  outer: Counters,
+  inner: Counters,
+}
+
+impl Drop for Resume {
+  fn drop(&mut self) {
+    let (outer, inner) = (self.outer, self.inner);
+    COUNTERS.set(if outer.on {
+      Counters {
+        on: true,
+        allocations: outer.allocations.saturating_add(inner.allocations),
+        bytes: outer.bytes.saturating_add(inner.bytes),
+        live: outer.live.saturating_add(inner.live),
+        peak: outer.peak.max(outer.live.saturating_add(inner.peak)),
+      }
+    } else {
+      outer
+    });
+  }
+}
+
+/// Stops the sampler of a [`measure`], also when the measured function panics.
+struct Stop<'a>(&'a AtomicBool);
+
+impl Drop for Stop<'_> {
+  fn drop(&mut self) {
+    self.0.store(false, Ordering::Relaxed);
+  }
+}
+
+/// Current resident set size of the process in bytes, or `None` where `/proc` does not tell.
+pub fn resident_set_size() -> Option<u64> {
+  let status = fs::read_to_string("/proc/self/status").ok()?;
+  let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+  let kilobytes: u64 = line
+    .trim_start_matches("VmRSS:")
+    .trim()
+    .trim_end_matches("kB")
+    .trim()
+    .parse()
+    .ok()?;
+  Some(kilobytes * 1024)
+}
+
+/// A byte count in binary units, e.g. `1.5 MiB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if self.0 < 1024 {
+      return write!(f, "{} B", self.0);
+    }
+    let mut size = self.0 as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+      size /= 1024.0;
+      unit += 1;
+    }
+    write!(f, "{:.1} {}", size, UNITS[unit])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::hint::black_box;
+
+  // The library's tests run with the counting allocator too; it only counts inside `measure`.
+  #[global_allocator]
+  static ALLOCATOR: Counting = Counting;
+
+  #[test]
+  fn test_measure_counts_allocations() {
+    let (_, usage) = measure(|| {
+      let kept = black_box(vec![0u8; 1000]);
+      drop(black_box(vec![0u8; 3000]));
+      kept
+    });
+    assert_eq!((usage.allocations, usage.bytes), (2, 4000));
+    assert_eq!(usage.peak, 4000);
+
+    let (_, usage) = measure(|| black_box(1 + 1));
+    assert_eq!((usage.allocations, usage.bytes, usage.peak), (0, 0, 0));
+  }
+
+  #[test]
+  fn test_nested_measure_adds_up() {
+    let (inner, outer) = measure(|| {
+      let _first = black_box(vec![0u8; 100]);
+      measure(|| black_box(vec![0u8; 200])).1
+    });
+    assert_eq!((inner.allocations, inner.bytes), (1, 200));
+    assert_eq!((outer.allocations, outer.bytes, outer.peak), (2, 300, 300));
+  }
+
+  #[test]
+  fn test_bytes_display() {
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+    assert_eq!(Bytes(3 << 30).to_string(), "3.0 GiB");
+  }
+}
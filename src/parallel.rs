@@ -0,0 +1,177 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::verify::catch_panic;
+
+/// How [`map`] spreads its jobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+  /// Worker threads, at least 1.
+  pub jobs: usize,
+  /// How long one job may run before it is given up on; `None` waits forever.
+  pub timeout: Option<Duration>,
+}
+
+impl Default for Options {
+  fn default() -> Self {
+    Options {
+      jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+      timeout: Some(Duration::from_secs(60)),
+    }
+  }
+}
+
+/// Why a job has no result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+  /// The job panicked with this message.
+  Panic(String),
+  /// The job was still running when its time was up.
+  Timeout,
+}
+
+enum Message<T> {
+  Started(usize, Instant),
+  Finished(usize, Result<T, String>),
+}
+
+/// Applies `f` to every item on a pool of worker threads and returns the results in item order.
+///
+/// A panic only fails its own job. A job that outlives the timeout is reported as
+/// [`Failure::Timeout`] and abandoned: threads cannot be stopped, so it keeps running in the
+/// background while a new worker takes its place, and it ends when the process does.
+pub fn map<I, T, F>(items: Vec<I>, options: Options, f: F) -> Vec<Result<T, Failure>>
+where
+  I: Send + 'static,
+  T: Send + 'static,
+  F: Fn(I) -> T + Send + Sync + 'static,
+{
+  let total = items.len();
+  let queue = Arc::new(Mutex::new(
+    items.into_iter().enumerate().collect::<VecDeque<_>>(),
+  ));
+  let f = Arc::new(f);
+  let (sender, receiver) = mpsc::channel();
+  let spawn_worker = |sender: &Sender<Message<T>>| {
+    let (queue, f, sender) = (Arc::clone(&queue), Arc::clone(&f), sender.clone());
+    thread::spawn(move || {
+      loop {
+        let Some((index, item)) = queue.lock().unwrap().pop_front() else {
+          break;
+        };
+        if sender
+          .send(Message::Started(index, Instant::now()))
+          .is_err()
+        {
+          break;
+        }
+        let result = catch_panic(|| f(item));
+        if sender.send(Message::Finished(index, result)).is_err() {
+          break;
+        }
+      }
+    });
+  };
+  for _ in 0..options.jobs.max(1).min(total) {
+    spawn_worker(&sender);
+  }
+
+  let mut results: Vec<Option<Result<T, Failure>>> = (0..total).map(|_| None).collect();
+  let mut running: HashMap<usize, Instant> = HashMap::new();
+  let mut finished = 0;
+  while finished < total {
+    let deadline = options
+      .timeout
+      .and_then(|timeout| Some(*running.values().min()? + timeout));
+    let message = match deadline {
+      Some(deadline) => {
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+          Ok(message) => Some(message),
+          Err(RecvTimeoutError::Timeout) => None,
+          Err(RecvTimeoutError::Disconnected) => unreachable!("a sender is kept for new workers"),
+        }
+      }
+      None => Some(receiver.recv().expect("a sender is kept for new workers")),
+    };
+
+    match message {
+      Some(Message::Started(index, start)) => {
+        running.insert(index, start);
+      }
+      // A job that already timed out is no longer running, and its late result is dropped.
+      Some(Message::Finished(index, result)) => {
+        if running.remove(&index).is_some() {
+          results[index] = Some(result.map_err(Failure::Panic));
+          finished += 1;
+        }
+      }
+      None => {
+        let timeout = options.timeout.expect("only a timeout ends the wait early");
+        let now = Instant::now();
+        let expired: Vec<usize> = running
+          .iter()
+          .filter(|&(_, &start)| now >= start + timeout)
+          .map(|(&index, _)| index)
+          .collect();
+        for index in expired {
+          running.remove(&index);
+          results[index] = Some(Err(Failure::Timeout));
+          finished += 1;
+          spawn_worker(&sender);
+        }
+      }
+    }
+  }
+
+  results
+    .into_iter()
+    .map(|result| result.expect("every job finished or timed out"))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_map_keeps_order() {
+    let options = Options {
+      jobs: 3,
+      timeout: None,
+    };
+    let squares = map((0..20u64).collect(), options, |n| {
+      thread::sleep(Duration::from_millis(20 - n));
+      n * n
+    });
+    assert_eq!(squares, (0..20u64).map(|n| Ok(n * n)).collect::<Vec<_>>());
+    assert!(map(Vec::<u8>::new(), options, |n| n).is_empty());
+  }
+
+  #[test]
+  fn test_map_isolates_panics_and_hangs() {
+    let options = Options {
+      jobs: 2,
+      timeout: Some(Duration::from_millis(200)),
+    };
+    let results = map(vec![1, 2, 3, 4, 5], options, |n| match n {
+      2 => panic!("bad input {}", n),
+      3 | 4 => loop {
+        thread::sleep(Duration::from_millis(10));
+      },
+      _ => n * 10,
+    });
+    assert_eq!(
+      results,
+      vec![
+        Ok(10),
+        Err(Failure::Panic("bad input 2".to_string())),
+        Err(Failure::Timeout),
+        Err(Failure::Timeout),
+        Ok(50),
+      ]
+    );
+  }
+}
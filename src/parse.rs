@@ -0,0 +1,225 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// Why a day's input could not be parsed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+  /// Filled in by [`crate::Day::parse`]; `None` when a solution's parser is called directly.
+  pub day: Option<u8>,
+  /// `None` when the problem concerns the input as a whole, such as a missing start tile.
+  pub location: Option<Location>,
+  pub message: String,
+}
+
+/// Position of the offending text, with 1-based line and column numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+  pub line: usize,
+  pub column: usize,
+  pub text: String,
+}
+
+impl ParseError {
+  /// An error about the input as a whole rather than a particular line.
+  pub fn new(message: impl Into<String>) -> Self {
+    ParseError {
+      day: None,
+      location: None,
+      message: message.into(),
+    }
+  }
+
+  pub fn in_day(mut self, day: u8) -> Self {
+    self.day = Some(day);
+    self
+  }
+}
+
+impl Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if let Some(day) = self.day {
+      write!(f, "day {}: ", day)?;
+    }
+    match &self.location {
+      Some(location) => write!(
+        f,
+        "line {}, column {}: {} `{}`",
+        location.line, location.column, self.message, location.text
+      ),
+      None => write!(f, "{}", self.message),
+    }
+  }
+}
+
+impl Error for ParseError {}
+
+/// One line of input with its 1-based line number, for building [`ParseError`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+  pub number: usize,
+  pub text: &'a str,
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+  input.lines().enumerate().map(|(i, text)| Line {
+    number: i + 1,
+    text,
+  })
+}
+
+/// The blank-line separated sections of `input`, each as its numbered lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+  let mut sections = vec![Vec::new()];
+  for line in lines(input) {
+    if line.text.trim().is_empty() {
+      if !sections.last().unwrap().is_empty() {
+        sections.push(Vec::new());
+      }
+    } else {
+      sections.last_mut().unwrap().push(line);
+    }
+  }
+  sections.retain(|section| !section.is_empty());
+  sections
+}
+
+/// A rectangular grid with one cell per character of `lines`, mapped through `cell`. Characters
+/// for which `cell` returns `None` are reported as an invalid `what`, as are a missing grid and
+/// ragged rows.
+pub fn grid<'a, T>(
+  lines: impl IntoIterator<Item = Line<'a>>,
+  what: &str,
+  mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+  let mut cells = Vec::new();
+  let mut width = None;
+  let mut height = 0;
+
+  for line in lines {
+    let row = line
+      .text
+      .char_indices()
+      .map(|(i, c)| {
+        cell(c)
+          .ok_or_else(|| line.error(&line.text[i..i + c.len_utf8()], format!("invalid {}", what)))
+      })
+      .collect::<Result<Vec<T>, _>>()?;
+
+    if row.is_empty() {
+      return Err(line.error(line.text, "empty grid row"));
+    }
+    if let Some(width) = width
+      && width != row.len()
+    {
+      return Err(line.error(line.text, format!("expected a row of width {}, got", width)));
+    }
+    width = Some(row.len());
+    height += 1;
+    cells.extend(row);
+  }
+
+  match width {
+    Some(width) => Ok(Grid::from_cells(width, height, cells)),
+    None => Err(ParseError::new("empty grid")),
+  }
+}
+
+impl<'a> Line<'a> {
+  /// An error pointing at `part`, which should be a slice of this line; any other string is
+  /// reported at the start of the line.
+  pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+    let start = self.text.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize)
+      .checked_sub(start)
+      .filter(|&offset| offset + part.len() <= self.text.len())
+      .unwrap_or(0);
+
+    ParseError {
+      day: None,
+      location: Some(Location {
+        line: self.number,
+        column: self.text[..offset].chars().count() + 1,
+        text: part.to_string(),
+      }),
+      message: message.into(),
+    }
+  }
+
+  /// An error for a line that does not have the expected shape, e.g. `expected("a~b")`.
+  pub fn expected(&self, form: &str) -> ParseError {
+    self.error(self.text, format!("expected `{}`, got", form))
+  }
+
+  /// Parses `part`, a slice of this line, reporting it as an invalid `what` on failure.
+  pub fn parse<T: FromStr>(&self, part: &str, what: &str) -> Result<T, ParseError> {
+    part
+      .trim()
+      .parse()
+      .map_err(|_| self.error(part, format!("invalid {}", what)))
+  }
+
+  /// The first character of `part`, a slice of this line.
+  pub fn char_at(&self, part: &str) -> Result<char, ParseError> {
+    part
+      .chars()
+      .next()
+      .ok_or_else(|| self.error(part, "missing character"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_error_points_at_part() {
+    let line = lines("a\nfoo 1x bar").nth(1).unwrap();
+    let error = line.parse::<u32>(&line.text[4..6], "number").unwrap_err();
+    assert_eq!(
+      error.location,
+      Some(Location {
+        line: 2,
+        column: 5,
+        text: "1x".to_string(),
+      })
+    );
+    assert_eq!(
+      error.in_day(7).to_string(),
+      "day 7: line 2, column 5: invalid number `1x`"
+    );
+    assert_eq!(line.error("elsewhere", "bad").location.unwrap().column, 1);
+  }
+
+  #[test]
+  fn test_sections() {
+    let sections = sections("a\nb\n\n\nc\n");
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections[1][0].number, 5);
+    assert_eq!(sections[1][0].text, "c");
+  }
+
+  #[test]
+  fn test_grid() {
+    let digit = |c: char| c.to_digit(10);
+    assert_eq!(
+      grid(lines("12\n34\n"), "digit", digit),
+      Ok(Grid::from_cells(2, 2, vec![1, 2, 3, 4]))
+    );
+    let error = grid(lines("12\n3x\n"), "digit", digit).unwrap_err();
+    assert_eq!(error.to_string(), "line 2, column 2: invalid digit `x`");
+    assert!(grid(lines("12\n345\n"), "digit", digit).is_err());
+    assert!(grid(lines(""), "digit", digit).is_err());
+  }
+
+  #[test]
+  fn test_whole_input_error() {
+    assert_eq!(
+      ParseError::new("no start tile").in_day(10).to_string(),
+      "day 10: no start tile"
+    );
+  }
+}
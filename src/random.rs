@@ -0,0 +1,75 @@
+//! Seeds for the solvers and tools that make random choices.
+//!
+//! Randomized solvers take their generator from [`rng`] rather than from the operating system,
+//! so that a run can be repeated exactly: inside [`with_seed`], every generator starts from the
+//! given seed, and the same seed takes a solver down the same path to its answer in the same
+//! number of steps. Outside of `with_seed` each generator gets a fresh seed, as before.
+
+use std::cell::Cell;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+thread_local! {
+  static SEED: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with `seed` for the generators it takes from [`rng`] on this thread.
+///
+/// Seeds nest: an inner `with_seed` replaces the outer seed until it returns.
+pub fn with_seed<T>(seed: u64, f: impl FnOnce() -> T) -> T {
+  /// Puts the outer seed back, also when `f` panics.
+  struct Restore(Option<u64>);
+
+  impl Drop for Restore {
+    fn drop(&mut self) {
+      SEED.set(self.0);
+    }
+  }
+
+  let _restore = Restore(SEED.replace(Some(seed)));
+  f()
+}
+
+/// The seed of the enclosing [`with_seed`] on this thread, if any.
+pub fn seed() -> Option<u64> {
+  SEED.get()
+}
+
+/// A generator starting from the seed of the enclosing [`with_seed`], or from a fresh seed.
+///
+/// Every call starts over from the seed, so a solver should take one generator and draw all its
+/// numbers from it.
+pub fn rng() -> StdRng {
+  StdRng::seed_from_u64(seed().unwrap_or_else(new_seed))
+}
+
+/// A seed drawn from the operating system, for runs that are not given one.
+pub fn new_seed() -> u64 {
+  rand::random()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::Rng;
+
+  fn draw() -> u64 {
+    rng().random()
+  }
+
+  #[test]
+  fn test_same_seed_same_numbers() {
+    assert_eq!(with_seed(7, draw), with_seed(7, draw));
+    assert_ne!(with_seed(7, draw), with_seed(8, draw));
+  }
+
+  #[test]
+  fn test_seed_ends_with_scope() {
+    assert_eq!(seed(), None);
+    let (inner, outer) = with_seed(1, || (with_seed(2, seed), seed()));
+    assert_eq!((inner, outer), (Some(2), Some(1)));
+    let _ = std::panic::catch_unwind(|| with_seed(3, || panic!("boom")));
+    assert_eq!(seed(), None);
+  }
+}
@@ -0,0 +1,601 @@
+use std::fmt::{self, Display};
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::time::Duration;
+use std::{env, fs};
+
+use crate::input::input_path;
+use crate::{Answer, Part};
+
+/// Environment variable holding the session cookie of a logged-in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable that overrides [`DEFAULT_BASE_URL`], e.g. to point at a local stub.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Year of the puzzles when neither `--year` nor the [`CONFIG_FILE`] gives one.
+pub const YEAR: u16 = 2023;
+/// File in the root of a checkout recording the year of its puzzles as a `year = N` line.
+pub const CONFIG_FILE: &str = "aoc.conf";
+
+const USER_AGENT: &str = concat!("aoc2023/", env!("CARGO_PKG_VERSION"));
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Talks to the Advent of Code website, or anything serving the same paths.
+///
+/// Plain `http://` URLs are handled in-process. The standard library has no TLS, so `https://`
+/// requests go through the `curl` command instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client {
+  base_url: String,
+  year: u16,
+  session: Option<String>,
+}
+
+/// Whether [`fetch_input`] found the input on disk or had to download it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetch {
+  Cached,
+  Downloaded,
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+  Correct,
+  Wrong,
+  TooHigh,
+  TooLow,
+  /// The part was already solved, so the answer was not judged.
+  AlreadySolved,
+  /// Another answer was submitted too recently; holds the remaining wait, e.g. `1m 3s`.
+  RateLimited(Option<String>),
+}
+
+#[derive(Debug)]
+struct Response {
+  status: u16,
+  body: String,
+}
+
+impl Client {
+  /// A client for the puzzles of `year`.
+  pub fn new(base_url: &str, year: u16, session: Option<String>) -> Client {
+    Client {
+      base_url: base_url.trim_end_matches('/').to_string(),
+      year,
+      session,
+    }
+  }
+
+  /// A client for the puzzles of `year` at `$AOC_BASE_URL` (or [`DEFAULT_BASE_URL`]) using the
+  /// token from [`session_token`]. A missing token is only an error once a request is made.
+  pub fn from_env(year: u16) -> Result<Client, String> {
+    let base_url = env::var(BASE_URL_VAR)
+      .ok()
+      .filter(|url| !url.is_empty())
+      .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+    Ok(Client::new(&base_url, year, session_token()?))
+  }
+
+  /// The personal puzzle input for `day`.
+  pub fn input(&self, day: u8) -> Result<String, String> {
+    let path = format!("/{}/day/{}/input", self.year, day);
+    let response = self.request(&path, None)?;
+    if response.status != 200 {
+      return Err(self.failure(&path, &response));
+    }
+    Ok(response.body)
+  }
+
+  /// Submits `answer` to `part` of `day` and reads the verdict off the page that comes back.
+  pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<Verdict, String> {
+    let path = format!("/{}/day/{}/answer", self.year, day);
+    let form = format!("level={}&answer={}", part, form_encode(&answer.to_string()));
+    let response = self.request(&path, Some(&form))?;
+    if response.status != 200 {
+      return Err(self.failure(&path, &response));
+    }
+    Verdict::from_page(&response.body).ok_or_else(|| {
+      format!(
+        "{}{} answered with an unrecognized page",
+        self.base_url, path
+      )
+    })
+  }
+
+  /// Sends a GET, or a form POST when `form` is given, to `path` below the base URL.
+  fn request(&self, path: &str, form: Option<&str>) -> Result<Response, String> {
+    let session = self.session.as_deref().ok_or_else(|| {
+      let config = config_path().map_or("a config file".to_string(), |path| {
+        path.display().to_string()
+      });
+      format!(
+        "No session token: set {} or write it to {}",
+        SESSION_VAR, config
+      )
+    })?;
+    let url = format!("{}{}", self.base_url, path);
+
+    if let Some(address) = url.strip_prefix("http://") {
+      http_request(address, session, form)
+    } else if url.starts_with("https://") {
+      curl_request(&url, session, form)
+    } else {
+      Err(format!(
+        "Unsupported base URL {}: expected http:// or https://",
+        self.base_url
+      ))
+    }
+    .map_err(|e| format!("Request to {} failed: {}", url, e))
+  }
+
+  fn failure(&self, path: &str, response: &Response) -> String {
+    let reason = response.body.lines().next().unwrap_or("").trim();
+    format!(
+      "{}{} answered HTTP {}: {}",
+      self.base_url, path, response.status, reason
+    )
+  }
+}
+
+impl Verdict {
+  /// Recognizes the sentences the website uses for each outcome.
+  pub fn from_page(page: &str) -> Option<Verdict> {
+    if page.contains("That's the right answer") {
+      Some(Verdict::Correct)
+    } else if page.contains("That's not the right answer") {
+      Some(if page.contains("your answer is too high") {
+        Verdict::TooHigh
+      } else if page.contains("your answer is too low") {
+        Verdict::TooLow
+      } else {
+        Verdict::Wrong
+      })
+    } else if page.contains("You gave an answer too recently") {
+      let wait = page
+        .split_once("You have ")
+        .and_then(|(_, rest)| rest.split_once(" left to wait"))
+        .map(|(wait, _)| wait.to_string());
+      Some(Verdict::RateLimited(wait))
+    } else if page.contains("You don't seem to be solving the right level") {
+      Some(Verdict::AlreadySolved)
+    } else {
+      None
+    }
+  }
+
+  /// Whether the verdict says something lasting about the answer, as opposed to the request.
+  pub fn is_judgement(&self) -> bool {
+    matches!(
+      self,
+      Verdict::Correct | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+    )
+  }
+}
+
+impl Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Verdict::Correct => write!(f, "correct"),
+      Verdict::Wrong => write!(f, "wrong"),
+      Verdict::TooHigh => write!(f, "too-high"),
+      Verdict::TooLow => write!(f, "too-low"),
+      Verdict::AlreadySolved => write!(f, "already-solved"),
+      Verdict::RateLimited(_) => write!(f, "rate-limited"),
+    }
+  }
+}
+
+/// Parses the judgements, the only verdicts worth writing down.
+impl FromStr for Verdict {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "correct" => Ok(Verdict::Correct),
+      "wrong" => Ok(Verdict::Wrong),
+      "too-high" => Ok(Verdict::TooHigh),
+      "too-low" => Ok(Verdict::TooLow),
+      _ => Err(format!("Invalid verdict: {}", s)),
+    }
+  }
+}
+
+/// Percent-encodes everything but ASCII letters, digits and `-._~`.
+fn form_encode(value: &str) -> String {
+  value
+    .bytes()
+    .map(|b| match b {
+      b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+        (b as char).to_string()
+      }
+      _ => format!("%{:02X}", b),
+    })
+    .collect()
+}
+
+/// Where the session token is read from when `$AOC_SESSION` is unset:
+/// `$XDG_CONFIG_HOME/aoc/session`, or `~/.config/aoc/session`.
+pub fn config_path() -> Option<PathBuf> {
+  let non_empty = |name| env::var_os(name).filter(|value| !value.is_empty());
+  non_empty("XDG_CONFIG_HOME")
+    .map(PathBuf::from)
+    .or_else(|| non_empty("HOME").map(|home| PathBuf::from(home).join(".config")))
+    .map(|dir| dir.join("aoc").join("session"))
+}
+
+/// The year recorded in the [`CONFIG_FILE`] at `path`, or `None` if there is no such file or it
+/// records none.
+pub fn load_year(path: &Path) -> Result<Option<u16>, String> {
+  match fs::read_to_string(path) {
+    Ok(text) => parse_year(&text).map_err(|e| format!("{}:{}", path.display(), e)),
+    Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+    Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+  }
+}
+
+/// The year in the text of a [`CONFIG_FILE`], whose lines are `key = value` pairs, comments
+/// starting with `#` or blank. `year` is the only key.
+pub fn parse_year(text: &str) -> Result<Option<u16>, String> {
+  let mut year = None;
+  for (i, line) in text.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    match line
+      .split_once('=')
+      .map(|(key, value)| (key.trim(), value.trim()))
+    {
+      Some(("year", value)) => {
+        year = Some(
+          value
+            .parse()
+            .ok()
+            .filter(|year| *year >= 2015)
+            .ok_or_else(|| format!("{}: invalid year: {}", i + 1, value))?,
+        );
+      }
+      Some((key, _)) => return Err(format!("{}: unknown key: {}", i + 1, key)),
+      None => return Err(format!("{}: expected `key = value`", i + 1)),
+    }
+  }
+  Ok(year)
+}
+
+/// The session token from `$AOC_SESSION` or the [`config_path`] file, or `None` if neither is
+/// set. Errors if the token could not be sent as a cookie.
+pub fn session_token() -> Result<Option<String>, String> {
+  let token = match env::var(SESSION_VAR) {
+    Ok(token) if !token.trim().is_empty() => token,
+    _ => match config_path().filter(|path| path.is_file()) {
+      Some(path) => fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?,
+      None => return Ok(None),
+    },
+  };
+
+  let token = token.trim();
+  if token.is_empty() {
+    return Ok(None);
+  }
+  if !token.chars().all(|c| c.is_ascii_graphic() && c != ';') {
+    return Err("Invalid session token: expected the value of the `session` cookie".to_string());
+  }
+  Ok(Some(token.to_string()))
+}
+
+/// Makes sure `dNN_full.txt` for `day` exists in `dir`, downloading it only when it does not.
+/// A cached input is never downloaded again, so delete the file to refresh it. An empty file,
+/// such as the placeholder `aoc new` creates, does not count as cached.
+pub fn fetch_input(client: &Client, dir: &Path, day: u8) -> Result<(PathBuf, Fetch), String> {
+  let path = input_path(dir, day, "full");
+  if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+    return Ok((path, Fetch::Cached));
+  }
+
+  let text = client.input(day)?;
+  fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+  // Write next to the destination and rename, so an interrupted download never looks cached.
+  let partial = path.with_extension("txt.part");
+  fs::write(&partial, text)
+    .and_then(|()| fs::rename(&partial, &path))
+    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+  Ok((path, Fetch::Downloaded))
+}
+
+/// A minimal HTTP/1.0 exchange, which keeps the response free of chunked encoding.
+fn http_request(address: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+  let (authority, path) = match address.find('/') {
+    Some(i) => address.split_at(i),
+    None => (address, "/"),
+  };
+  let host = authority
+    .rsplit_once(':')
+    .map_or(authority, |(host, _)| host);
+  let port = match authority.rsplit_once(':') {
+    Some((_, port)) => port.parse().map_err(|_| format!("invalid port {}", port))?,
+    None => 80,
+  };
+
+  let mut request = format!(
+    "{} {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\n",
+    if form.is_some() { "POST" } else { "GET" },
+    path,
+    authority,
+    USER_AGENT,
+    session
+  );
+  if let Some(form) = form {
+    request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+    request.push_str(&format!("Content-Length: {}\r\n", form.len()));
+  }
+  request.push_str("\r\n");
+  request.push_str(form.unwrap_or(""));
+
+  let mut stream = TcpStream::connect((host, port)).map_err(|e| e.to_string())?;
+  stream
+    .set_read_timeout(Some(TIMEOUT))
+    .map_err(|e| e.to_string())?;
+  stream
+    .write_all(request.as_bytes())
+    .map_err(|e| e.to_string())?;
+  let mut raw = Vec::new();
+  stream.read_to_end(&mut raw).map_err(|e| e.to_string())?;
+
+  let raw = String::from_utf8(raw).map_err(|_| "response is not UTF-8".to_string())?;
+  let (head, body) = raw
+    .split_once("\r\n\r\n")
+    .ok_or("response has no header terminator")?;
+  let status = head
+    .split_whitespace()
+    .nth(1)
+    .and_then(|status| status.parse().ok())
+    .ok_or_else(|| {
+      format!(
+        "malformed status line: {}",
+        head.lines().next().unwrap_or("")
+      )
+    })?;
+  Ok(Response {
+    status,
+    body: body.to_string(),
+  })
+}
+
+/// Runs `curl`, passing its options on standard input so the token stays out of the process
+/// list.
+fn curl_request(url: &str, session: &str, form: Option<&str>) -> Result<Response, String> {
+  let quote = |value: &str| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+  let mut config = format!(
+    "url = {}\nuser-agent = {}\nheader = {}\nwrite-out = \"\\n%{{http_code}}\"\n",
+    quote(url),
+    quote(USER_AGENT),
+    quote(&format!("Cookie: session={}", session))
+  );
+  if let Some(form) = form {
+    config.push_str(&format!("data = {}\n", quote(form)));
+  }
+
+  let mut child = Command::new("curl")
+    .args([
+      "--silent",
+      "--show-error",
+      "--max-time",
+      "30",
+      "--config",
+      "-",
+    ])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .map_err(|e| format!("https needs the curl command: {}", e))?;
+  child
+    .stdin
+    .take()
+    .expect("stdin is piped")
+    .write_all(config.as_bytes())
+    .map_err(|e| e.to_string())?;
+  let output = child.wait_with_output().map_err(|e| e.to_string())?;
+  if !output.status.success() {
+    return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+  }
+
+  let stdout = String::from_utf8(output.stdout).map_err(|_| "response is not UTF-8".to_string())?;
+  let (body, status) = stdout.rsplit_once('\n').ok_or("curl printed no status")?;
+  Ok(Response {
+    status: status
+      .parse()
+      .map_err(|_| format!("curl printed an invalid status: {}", status))?,
+    body: body.to_string(),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::temp_dir;
+  use std::net::TcpListener;
+  use std::sync::{Arc, Mutex};
+  use std::thread;
+
+  /// Serves one canned `(status, body)` response per connection, in order, then stops
+  /// listening. Returns the base URL and the requests received so far.
+  fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = Arc::clone(&requests);
+
+    thread::spawn(move || {
+      for (status, body) in responses {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+        while !complete(&request) {
+          let n = stream.read(&mut buffer).unwrap();
+          if n == 0 {
+            break;
+          }
+          request.extend_from_slice(&buffer[..n]);
+        }
+        received
+          .lock()
+          .unwrap()
+          .push(String::from_utf8(request).unwrap());
+        let response = format!(
+          "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+          status,
+          body.len(),
+          body
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+      }
+    });
+
+    (base_url, requests)
+  }
+
+  /// Whether `request` holds the headers and as much body as its Content-Length announces.
+  fn complete(request: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(request);
+    let Some((head, body)) = text.split_once("\r\n\r\n") else {
+      return false;
+    };
+    let length = head
+      .lines()
+      .find_map(|line| line.strip_prefix("Content-Length: "))
+      .map_or(0, |length| length.parse().unwrap());
+    body.len() >= length
+  }
+
+  #[test]
+  fn test_fetch_downloads_once() {
+    let (base_url, requests) = stub_server(vec![(200, "0 3 6 9 12 15\n")]);
+    let client = Client::new(&format!("{}/", base_url), 2024, Some("abc123".to_string()));
+    let dir = temp_dir("fetch-once");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("d09_full.txt"), "").unwrap();
+
+    let (path, fetch) = fetch_input(&client, &dir, 9).unwrap();
+    assert_eq!(fetch, Fetch::Downloaded);
+    assert_eq!(path, dir.join("d09_full.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "0 3 6 9 12 15\n");
+
+    let request = requests.lock().unwrap()[0].clone();
+    assert!(request.starts_with("GET /2024/day/9/input HTTP/1.0\r\n"));
+    assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+
+    // The stub has stopped listening, so this only succeeds without a request.
+    assert_eq!(
+      fetch_input(&client, &dir, 9).unwrap(),
+      (path, Fetch::Cached)
+    );
+    assert_eq!(requests.lock().unwrap().len(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn test_fetch_reports_http_errors() {
+    let (base_url, _) = stub_server(vec![(400, "Puzzle inputs differ by user.\n")]);
+    let client = Client::new(&base_url, YEAR, Some("expired".to_string()));
+    let dir = temp_dir("fetch-error");
+
+    let error = fetch_input(&client, &dir, 1).unwrap_err();
+    assert!(
+      error.contains("HTTP 400: Puzzle inputs differ by user."),
+      "{}",
+      error
+    );
+    assert!(!input_path(&dir, 1, "full").exists());
+
+    let anonymous = Client::new(&base_url, YEAR, None);
+    assert!(anonymous.input(1).unwrap_err().contains(SESSION_VAR));
+    assert!(
+      Client::new("ftp://example.com", YEAR, Some("x".into()))
+        .input(1)
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn test_submit_parses_verdicts() {
+    let (base_url, requests) = stub_server(vec![
+      (
+        200,
+        "<p>That's the right answer! You are one gold star closer.</p>",
+      ),
+      (
+        200,
+        "<p>That's not the right answer; your answer is too low.</p>",
+      ),
+      (
+        200,
+        "<p>You gave an answer too recently. You have 42s left to wait.</p>",
+      ),
+      (200, "<html>Something else entirely</html>"),
+    ]);
+    let client = Client::new(&base_url, YEAR, Some("abc123".to_string()));
+    let submit = |answer: &str| client.submit(7, Part::Two, &answer.parse().unwrap());
+
+    assert_eq!(submit("251421071"), Ok(Verdict::Correct));
+    let request = requests.lock().unwrap()[0].clone();
+    assert!(request.starts_with("POST /2023/day/7/answer HTTP/1.0\r\n"));
+    assert!(request.ends_with("\r\n\r\nlevel=2&answer=251421071"));
+
+    assert_eq!(submit("12"), Ok(Verdict::TooLow));
+    assert_eq!(
+      submit("a b&c"),
+      Ok(Verdict::RateLimited(Some("42s".to_string())))
+    );
+    assert!(requests.lock().unwrap()[2].ends_with("answer=a%20b%26c"));
+    assert!(submit("13").is_err());
+  }
+
+  #[test]
+  fn test_verdict_from_page() {
+    let wrong =
+      "That's not the right answer. If you're stuck, make sure you're using the full input";
+    assert_eq!(Verdict::from_page(wrong), Some(Verdict::Wrong));
+    assert_eq!(
+      Verdict::from_page("That's not the right answer; your answer is too high."),
+      Some(Verdict::TooHigh)
+    );
+    assert_eq!(
+      Verdict::from_page(
+        "You don't seem to be solving the right level. Did you already complete it?"
+      ),
+      Some(Verdict::AlreadySolved)
+    );
+    assert_eq!(
+      Verdict::from_page("You gave an answer too recently."),
+      Some(Verdict::RateLimited(None))
+    );
+    for verdict in [
+      Verdict::Correct,
+      Verdict::Wrong,
+      Verdict::TooHigh,
+      Verdict::TooLow,
+    ] {
+      assert_eq!(verdict.to_string().parse(), Ok(verdict));
+    }
+    assert!("rate-limited".parse::<Verdict>().is_err());
+  }
+
+  #[test]
+  fn test_parse_year() {
+    assert_eq!(parse_year("# Puzzles of\nyear = 2024\n"), Ok(Some(2024)));
+    assert_eq!(parse_year("\n# nothing yet\n"), Ok(None));
+    assert!(parse_year("year = 1999").is_err());
+    assert!(parse_year("yaer = 2024").is_err());
+    assert!(parse_year("2024").is_err());
+    assert_eq!(
+      load_year(&temp_dir("no-config").join(CONFIG_FILE)),
+      Ok(None)
+    );
+  }
+}
@@ -0,0 +1,187 @@
+//! Machine-readable reports of run and verify results.
+//!
+//! The JSON is written by hand, since the reports are flat and the crate has no JSON
+//! dependency. Answers are always strings: they may not fit in a JSON number.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::verify::{Check, Status};
+
+/// How the results of a run or verification are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+  /// Tab-separated lines for people.
+  #[default]
+  Text,
+  /// A JSON array with one object per result.
+  Json,
+}
+
+impl FromStr for Format {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "text" => Ok(Format::Text),
+      "json" => Ok(Format::Json),
+      _ => Err(format!("Invalid format: {} (expected text or json)", s)),
+    }
+  }
+}
+
+/// 64-bit FNV-1a hash of an input's normalized text. It is stable across platforms and
+/// releases, so reports from different runs can be compared by it.
+pub fn input_hash(text: &str) -> u64 {
+  text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+    (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+  })
+}
+
+/// `checks` as a JSON array, one object per line.
+///
+/// Every object has the keys `day`, `part`, `input`, `hash`, `seed`, `answer`, `seconds`,
+/// `allocations`, `bytes`, `peak_bytes`, `process_rss_bytes`, `status`, `expected` and `error`;
+/// those that do not apply are `null`. The memory numbers are those of
+/// [`Usage`](crate::memory::Usage), whose resident set size is the whole process's.
+pub fn to_json(checks: &[Check]) -> String {
+  let objects: Vec<String> = checks.iter().map(check_to_json).collect();
+  if objects.is_empty() {
+    "[]\n".to_string()
+  } else {
+    format!("[\n  {}\n]\n", objects.join(",\n  "))
+  }
+}
+
+fn check_to_json(check: &Check) -> String {
+  let (expected, error) = match &check.status {
+    Status::Fail { expected, .. } => (Some(expected.to_string()), None),
+    Status::Error(e) => (None, Some(e.as_str())),
+    Status::Pass | Status::Missing => (None, None),
+  };
+  let answer = check
+    .answer
+    .as_ref()
+    .map(|answer| string(&answer.to_string()));
+  let memory = check.memory;
+
+  format!(
+    "{{\"day\": {}, \"part\": {}, \"input\": {}, \"hash\": {}, \"seed\": {}, \"answer\": {}, \
+     \"seconds\": {}, \"allocations\": {}, \"bytes\": {}, \"peak_bytes\": {}, \
+     \"process_rss_bytes\": {}, \"status\": {}, \"expected\": {}, \"error\": {}}}",
+    check.day,
+    check.part,
+    string(&check.input),
+    nullable(check.hash.map(|hash| string(&format!("{:016x}", hash)))),
+    nullable(check.seed.map(|seed| string(&seed.to_string()))),
+    nullable(answer),
+    nullable(check.time.map(|time| time.as_secs_f64().to_string())),
+    nullable(memory.map(|usage| usage.allocations.to_string())),
+    nullable(memory.map(|usage| usage.bytes.to_string())),
+    nullable(memory.map(|usage| usage.peak.to_string())),
+    nullable(
+      memory
+        .and_then(|usage| usage.rss)
+        .map(|rss| rss.to_string())
+    ),
+    string(&check.status.to_string()),
+    nullable(expected.as_deref().map(string)),
+    nullable(error.map(string)),
+  )
+}
+
+fn nullable(value: Option<String>) -> String {
+  value.unwrap_or_else(|| "null".to_string())
+}
+
+/// `s` as a quoted JSON string.
+fn string(s: &str) -> String {
+  let mut quoted = String::with_capacity(s.len() + 2);
+  quoted.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => quoted.push_str("\\\""),
+      '\\' => quoted.push_str("\\\\"),
+      '\n' => quoted.push_str("\\n"),
+      '\t' => quoted.push_str("\\t"),
+      '\r' => quoted.push_str("\\r"),
+      c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+      c => quoted.push(c),
+    }
+  }
+  quoted.push('"');
+  quoted
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::memory::Usage;
+  use crate::{Answer, Part};
+  use std::time::Duration;
+
+  #[test]
+  fn test_parse_format() {
+    assert_eq!("json".parse(), Ok(Format::Json));
+    assert_eq!("text".parse(), Ok(Format::Text));
+    assert!("yaml".parse::<Format>().is_err());
+  }
+
+  #[test]
+  fn test_input_hash() {
+    assert_eq!(input_hash(""), 0xcbf29ce484222325);
+    assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+    assert_ne!(input_hash("1abc2\n"), input_hash("1abc3\n"));
+  }
+
+  #[test]
+  fn test_to_json() {
+    let checks = [
+      Check {
+        day: 1,
+        part: Part::One,
+        input: "simple".to_string(),
+        hash: Some(0xab),
+        seed: Some(18446744073709551615),
+        answer: Some(Answer::from(142)),
+        time: Some(Duration::from_millis(1500)),
+        memory: Some(Usage {
+          allocations: 3,
+          bytes: 4096,
+          peak: 1024,
+          rss: None,
+        }),
+        status: Status::Fail {
+          expected: Answer::from(143),
+          actual: Answer::from(142),
+        },
+      },
+      Check {
+        day: 1,
+        part: Part::Two,
+        input: "a \"b\"\\c".to_string(),
+        hash: None,
+        seed: None,
+        answer: None,
+        time: None,
+        memory: None,
+        status: Status::Error("line 1\n\tbad\u{1}".to_string()),
+      },
+    ];
+
+    assert_eq!(
+      to_json(&checks),
+      "[\n  \
+       {\"day\": 1, \"part\": 1, \"input\": \"simple\", \"hash\": \"00000000000000ab\", \
+       \"seed\": \"18446744073709551615\", \"answer\": \"142\", \"seconds\": 1.5, \
+       \"allocations\": 3, \"bytes\": 4096, \"peak_bytes\": 1024, \"process_rss_bytes\": null, \
+       \"status\": \"fail\", \"expected\": \"143\", \"error\": null},\n  \
+       {\"day\": 1, \"part\": 2, \"input\": \"a \\\"b\\\"\\\\c\", \"hash\": null, \
+       \"seed\": null, \"answer\": null, \"seconds\": null, \
+       \"allocations\": null, \"bytes\": null, \"peak_bytes\": null, \"process_rss_bytes\": null, \
+       \"status\": \"error\", \"expected\": null, \"error\": \"line 1\\n\\tbad\\u0001\"}\n\
+       ]\n"
+    );
+    assert_eq!(to_json(&[]), "[]\n");
+  }
+}
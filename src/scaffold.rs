@@ -0,0 +1,282 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{DEFAULT_ANSWERS_FILE, sidecar_path};
+use crate::input::{DEFAULT_INPUT_DIR, input_path};
+use crate::remote::{self, CONFIG_FILE};
+
+/// Adds an unsolved `day` to the checkout at `root`, which may be this repository or a copy of
+/// it started for another year. Returns the files created or changed.
+///
+/// Writes `src/days/dayNN.rs` with a [`crate::Solution`] skeleton titled `title` and registers
+/// it in `src/days/mod.rs`, creates empty `dNN_full.txt` and `dNN_simple.txt` inputs with a
+/// sidecar waiting for the example's answers, and appends a placeholder to `answers.txt`. The
+/// golden tests skip the sample while it is empty and its sidecar has no answers.
+/// Existing inputs, sidecars and answers are left alone, but the day itself must be new.
+///
+/// The puzzle is from `year`, or else from the year in the checkout's [`CONFIG_FILE`], or else
+/// from [`remote::YEAR`]. A config file without a year gets one, so that fetch and submit use
+/// it too; one with another year is an error.
+pub fn new_day(
+  root: &Path,
+  year: Option<u16>,
+  day: u8,
+  title: &str,
+) -> Result<Vec<PathBuf>, String> {
+  if !(1..=25).contains(&day) {
+    return Err(format!("Invalid day: {} (expected 1-25)", day));
+  }
+  let config = root.join(CONFIG_FILE);
+  let recorded = remote::load_year(&config)?;
+  let year = match (year, recorded) {
+    (Some(year), Some(recorded)) if year != recorded => {
+      return Err(format!(
+        "{} is for {}, not {}; start another year in a copy",
+        config.display(),
+        recorded,
+        year
+      ));
+    }
+    (year, recorded) => year.or(recorded).unwrap_or(remote::YEAR),
+  };
+  let days_dir = root.join("src").join("days");
+  let registry = days_dir.join("mod.rs");
+  let solution = days_dir.join(format!("day{:02}.rs", day));
+  if solution.exists() {
+    return Err(format!("{} already exists", solution.display()));
+  }
+
+  let text = fs::read_to_string(&registry)
+    .map_err(|e| format!("Failed to read {}: {}", registry.display(), e))?;
+  let text = register(&text, day).map_err(|e| format!("{}: {}", registry.display(), e))?;
+  write(&solution, &solution_template(year, day, title))?;
+  write(&registry, &text)?;
+  let mut changed = vec![solution, registry];
+
+  let input_dir = root.join(DEFAULT_INPUT_DIR);
+  fs::create_dir_all(&input_dir)
+    .map_err(|e| format!("Failed to create {}: {}", input_dir.display(), e))?;
+  let sample = input_path(&input_dir, day, "simple");
+  let placeholders = [
+    (input_path(&input_dir, day, "full"), String::new()),
+    (sample.clone(), String::new()),
+    (sidecar_path(&sample), sidecar_template(day)),
+  ];
+  for (path, text) in placeholders {
+    if !path.exists() {
+      write(&path, &text)?;
+      changed.push(path);
+    }
+  }
+
+  let answers = root.join(DEFAULT_ANSWERS_FILE);
+  let mut text = fs::read_to_string(&answers).unwrap_or_default();
+  let placeholder = format!("# {} 1 full ?", day);
+  if !text.lines().any(|line| line.starts_with(&placeholder)) {
+    if !text.is_empty() && !text.ends_with('\n') {
+      text.push('\n');
+    }
+    text.push_str(&format!(
+      "\n# Day {}: replace with the answers once the website accepts them.\n{}\n# {} 2 full ?\n",
+      day, placeholder, day
+    ));
+    write(&answers, &text)?;
+    changed.push(answers);
+  }
+
+  if recorded.is_none() {
+    let mut text = fs::read_to_string(&config)
+      .unwrap_or_else(|_| "# Year of the puzzles, for aoc fetch and submit.\n".to_string());
+    if !text.is_empty() && !text.ends_with('\n') {
+      text.push('\n');
+    }
+    text.push_str(&format!("year = {}\n", year));
+    write(&config, &text)?;
+    changed.push(config);
+  }
+
+  Ok(changed)
+}
+
+/// Adds `day` to the module list and to `ALL` in the text of `src/days/mod.rs`, keeping both
+/// in calendar order.
+fn register(text: &str, day: u8) -> Result<String, String> {
+  let module = |line: &str| {
+    line
+      .strip_prefix("pub mod day")
+      .and_then(|rest| rest.strip_suffix(';'))
+      .and_then(|number| number.parse::<u8>().ok())
+  };
+  let lines: Vec<&str> = text.lines().collect();
+  let mut days: Vec<u8> = lines.iter().filter_map(|line| module(line)).collect();
+  if days.contains(&day) {
+    return Err(format!("day {} is already registered", day));
+  }
+  days.push(day);
+  days.sort_unstable();
+
+  let all = lines
+    .iter()
+    .position(|line| line.starts_with("pub const ALL: [Day; "))
+    .ok_or("no `pub const ALL: [Day; N]` array")?;
+  let all_end = if lines[all].ends_with("[];") {
+    all
+  } else {
+    (all..lines.len())
+      .find(|&i| lines[i] == "];")
+      .ok_or("`ALL` is not closed by a `];` line")?
+  };
+  let modules = lines.iter().position(|line| module(line).is_some());
+  let insert_modules = modules.unwrap_or_else(|| {
+    lines
+      .iter()
+      .position(|line| *line == "use crate::Day;")
+      .unwrap_or(all)
+  });
+
+  let mut output = Vec::new();
+  for (i, line) in lines.iter().enumerate() {
+    if i == insert_modules {
+      output.extend(days.iter().map(|day| format!("pub mod day{:02};", day)));
+      if modules.is_none() {
+        output.push(String::new());
+      }
+    }
+    if module(line).is_some() || (all..=all_end).contains(&i) {
+      if i == all {
+        output.push(format!("pub const ALL: [Day; {}] = [", days.len()));
+        output.extend(
+          days
+            .iter()
+            .map(|day| format!("  Day::of::<day{0:02}::Day{0:02}>(),", day)),
+        );
+        output.push("];".to_string());
+      }
+      continue;
+    }
+    output.push(line.to_string());
+  }
+
+  Ok(output.join("\n") + "\n")
+}
+
+fn solution_template(year: u16, day: u8, title: &str) -> String {
+  format!(
+    r#"//! <https://adventofcode.com/{year}/day/{day}>
+
+use crate::{{Answer, ParseError, Solution, parse}};
+
+pub struct Day{day:02} {{
+  #[allow(dead_code)]
+  lines: Vec<String>,
+}}
+
+impl Solution for Day{day:02} {{
+  const DAY: u8 = {day};
+  const TITLE: &'static str = "{title}";
+
+  fn parse(input: &str) -> Result<Self, ParseError> {{
+    Ok(Day{day:02} {{
+      lines: parse::lines(input)
+        .map(|line| line.text.to_string())
+        .collect(),
+    }})
+  }}
+
+  fn part1(&self) -> Answer {{
+    Answer::None
+  }}
+
+  fn part2(&self) -> Answer {{
+    Answer::None
+  }}
+}}
+"#,
+    year = year,
+    day = day,
+    title = title.replace('\\', "\\\\").replace('"', "\\\""),
+  )
+}
+
+fn sidecar_template(day: u8) -> String {
+  format!(
+    "# Answers to the example in the day {} puzzle text, checked by `cargo test`.\n\
+     # part1 = \n\
+     # part2 = \n",
+    day
+  )
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+  fs::write(path, text).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::answers::{KnownAnswers, Sidecar};
+  use crate::temp_dir;
+
+  const REGISTRY: &str = "\
+pub mod day01;
+pub mod day03;
+
+use crate::Day;
+
+/// Every day of the calendar, in order.
+pub const ALL: [Day; 2] = [
+  Day::of::<day01::Day01>(),
+  Day::of::<day03::Day03>(),
+];
+";
+
+  #[test]
+  fn test_register_keeps_calendar_order() {
+    let text = register(REGISTRY, 2).unwrap();
+    assert!(text.starts_with("pub mod day01;\npub mod day02;\npub mod day03;\n\nuse crate::Day;"));
+    assert!(text.contains(
+      "pub const ALL: [Day; 3] = [\n  Day::of::<day01::Day01>(),\n  \
+       Day::of::<day02::Day02>(),\n  Day::of::<day03::Day03>(),\n];\n"
+    ));
+    assert!(register(REGISTRY, 3).is_err());
+
+    let empty = "use crate::Day;\n\npub const ALL: [Day; 0] = [];\n";
+    assert_eq!(
+      register(empty, 7).unwrap(),
+      "pub mod day07;\n\nuse crate::Day;\n\npub const ALL: [Day; 1] = [\n  \
+       Day::of::<day07::Day07>(),\n];\n"
+    );
+  }
+
+  #[test]
+  fn test_new_day_creates_files_once() {
+    let root = temp_dir("scaffold");
+    fs::create_dir_all(root.join("src/days")).unwrap();
+    fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+
+    let changed = new_day(&root, Some(2024), 2, "A \"Quoted\" Title").unwrap();
+    assert_eq!(changed.len(), 7);
+    let solution = fs::read_to_string(root.join("src/days/day02.rs")).unwrap();
+    assert!(solution.contains("https://adventofcode.com/2024/day/2"));
+    assert!(solution.contains("pub struct Day02 {"));
+    assert!(solution.contains(r#"const TITLE: &'static str = "A \"Quoted\" Title";"#));
+    assert!(root.join("input/d02_full.txt").is_file());
+
+    // The placeholders parse, and the golden tests skip the empty sample until it is filled in.
+    let sidecar = Sidecar::load(&root.join("input/d02_simple.expected")).unwrap();
+    assert!(sidecar.answers.is_empty());
+    let answers = KnownAnswers::load(&root.join("answers.txt")).unwrap();
+    assert!(answers.is_empty());
+
+    assert!(new_day(&root, Some(2024), 2, "Again").is_err());
+    assert!(new_day(&root, Some(2024), 26, "Too late").is_err());
+
+    // Later days keep to the year the first one recorded.
+    assert_eq!(remote::load_year(&root.join(CONFIG_FILE)), Ok(Some(2024)));
+    assert!(new_day(&root, Some(2023), 4, "Wrong year").is_err());
+    assert_eq!(new_day(&root, None, 4, "Day 4").unwrap().len(), 6);
+    let solution = fs::read_to_string(root.join("src/days/day04.rs")).unwrap();
+    assert!(solution.contains("https://adventofcode.com/2024/day/4"));
+    fs::remove_dir_all(&root).unwrap();
+  }
+}
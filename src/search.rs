@@ -0,0 +1,263 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A cheapest route found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+  pub cost: C,
+  /// Every state along the route, from the start to the goal inclusive.
+  pub states: Vec<S>,
+}
+
+/// Cheapest route from `start` to a state satisfying `is_goal`, where `successors` lists the
+/// states one move away with the cost of each move. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+  start: S,
+  successors: impl FnMut(&S) -> I,
+  is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+  S: Clone + Eq + Hash,
+  C: Copy + Ord + Default + Add<Output = C>,
+  I: IntoIterator<Item = (S, C)>,
+{
+  astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, an estimate of the remaining cost to a goal. The route is
+/// the cheapest as long as the heuristic never overestimates.
+pub fn astar<S, C, I>(
+  start: S,
+  mut successors: impl FnMut(&S) -> I,
+  mut heuristic: impl FnMut(&S) -> C,
+  mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+  S: Clone + Eq + Hash,
+  C: Copy + Ord + Default + Add<Output = C>,
+  I: IntoIterator<Item = (S, C)>,
+{
+  // States are numbered in the order they are found; the heap and parent links use the numbers,
+  // so states need not be ordered.
+  let mut states = vec![start.clone()];
+  let mut numbers = HashMap::from([(start, 0)]);
+  let mut best: Vec<(C, usize)> = vec![(C::default(), usize::MAX)];
+  let mut heap = BinaryHeap::from([Reverse((heuristic(&states[0]), C::default(), 0))]);
+
+  while let Some(Reverse((_, cost, current))) = heap.pop() {
+    if cost > best[current].0 {
+      continue;
+    }
+    if is_goal(&states[current]) {
+      return Some(Path {
+        cost,
+        states: reconstruct(&states, |i| best[i].1, current),
+      });
+    }
+
+    for (next, step) in successors(&states[current]) {
+      let next_cost = cost + step;
+      let number = match numbers.entry(next) {
+        Entry::Occupied(entry) => {
+          let number = *entry.get();
+          if next_cost >= best[number].0 {
+            continue;
+          }
+          best[number] = (next_cost, current);
+          number
+        }
+        Entry::Vacant(entry) => {
+          states.push(entry.key().clone());
+          best.push((next_cost, current));
+          *entry.insert(states.len() - 1)
+        }
+      };
+      heap.push(Reverse((
+        next_cost + heuristic(&states[number]),
+        next_cost,
+        number,
+      )));
+    }
+  }
+
+  None
+}
+
+/// Shortest route, counted in moves, from `start` to a state satisfying `is_goal`.
+pub fn bfs<S, I>(
+  start: S,
+  mut successors: impl FnMut(&S) -> I,
+  mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+  S: Clone + Eq + Hash,
+  I: IntoIterator<Item = S>,
+{
+  let mut states = vec![start.clone()];
+  let mut parents = vec![usize::MAX];
+  let mut seen = HashSet::from([start]);
+  let mut queue = VecDeque::from([0]);
+
+  while let Some(current) = queue.pop_front() {
+    if is_goal(&states[current]) {
+      let states = reconstruct(&states, |i| parents[i], current);
+      return Some(Path {
+        cost: states.len() - 1,
+        states,
+      });
+    }
+    for next in successors(&states[current]) {
+      if seen.insert(next.clone()) {
+        states.push(next);
+        parents.push(current);
+        queue.push_back(states.len() - 1);
+      }
+    }
+  }
+
+  None
+}
+
+/// The number of moves from `start` to every state reachable from it.
+pub fn bfs_distances<S, I>(start: S, successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+  S: Clone + Eq + Hash,
+  I: IntoIterator<Item = S>,
+{
+  bfs_distances_within(start, usize::MAX, successors)
+}
+
+/// [`bfs_distances`] limited to the states at most `max_distance` moves away, which also works
+/// on unbounded spaces.
+pub fn bfs_distances_within<S, I>(
+  start: S,
+  max_distance: usize,
+  mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+  S: Clone + Eq + Hash,
+  I: IntoIterator<Item = S>,
+{
+  let mut distances = HashMap::from([(start.clone(), 0)]);
+  let mut queue = VecDeque::from([(start, 0)]);
+
+  while let Some((state, distance)) = queue.pop_front() {
+    if distance == max_distance {
+      continue;
+    }
+    for next in successors(&state) {
+      if let Entry::Vacant(entry) = distances.entry(next) {
+        queue.push_back((entry.key().clone(), distance + 1));
+        entry.insert(distance + 1);
+      }
+    }
+  }
+
+  distances
+}
+
+/// Follows parent links back from `goal` and returns the states from the start onwards.
+fn reconstruct<S: Clone>(states: &[S], parent: impl Fn(usize) -> usize, goal: usize) -> Vec<S> {
+  let mut path = vec![states[goal].clone()];
+  let mut current = goal;
+  while parent(current) != usize::MAX {
+    current = parent(current);
+    path.push(states[current].clone());
+  }
+  path.reverse();
+  path
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::grid::{Coord, Grid};
+  use crate::parse;
+
+  const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.";
+
+  fn maze() -> Grid<bool> {
+    parse::grid(parse::lines(MAZE), "tile", |c| Some(c == '#')).unwrap()
+  }
+
+  fn open_neighbors(maze: &Grid<bool>, coord: Coord) -> Vec<Coord> {
+    maze.neighbors4(coord).filter(|&next| !maze[next]).collect()
+  }
+
+  #[test]
+  fn test_bfs_finds_shortest_path() {
+    let maze = maze();
+    let goal = Coord::new(6, 0);
+    let path = bfs(
+      Coord::new(0, 0),
+      |&c| open_neighbors(&maze, c),
+      |&c| c == goal,
+    )
+    .unwrap();
+
+    assert_eq!(path.cost, 12);
+    assert_eq!(path.states.len(), 13);
+    assert_eq!(path.states.first(), Some(&Coord::new(0, 0)));
+    assert_eq!(path.states.last(), Some(&goal));
+    assert!(
+      path
+        .states
+        .windows(2)
+        .all(|w| maze.neighbors4(w[0]).any(|n| n == w[1]))
+    );
+    assert!(bfs(Coord::new(0, 0), |&c| open_neighbors(&maze, c), |_| false).is_none());
+  }
+
+  #[test]
+  fn test_bfs_distances() {
+    let maze = maze();
+    let distances = bfs_distances(Coord::new(0, 0), |&c| open_neighbors(&maze, c));
+    assert_eq!(distances.len(), maze.find_all(&false).count());
+    assert_eq!(distances[&Coord::new(6, 0)], 12);
+
+    let near = bfs_distances_within(Coord::new(0, 0), 2, |&c| open_neighbors(&maze, c));
+    assert_eq!(near.len(), 4);
+
+    // An unbounded line: only the limit stops the search.
+    let line = bfs_distances_within(0i64, 3, |&x| [x - 1, x + 1]);
+    assert_eq!(line.len(), 7);
+  }
+
+  #[test]
+  fn test_dijkstra_prefers_cheap_moves() {
+    // 0 -> 1 -> 2 -> 3 costs 1 each, the direct 0 -> 3 costs 5.
+    let edges = |&node: &u8| match node {
+      0 => vec![(1, 1), (3, 5)],
+      1 => vec![(2, 1)],
+      2 => vec![(3, 1)],
+      _ => vec![],
+    };
+    let path = dijkstra(0u8, edges, |&node| node == 3).unwrap();
+    assert_eq!(path.cost, 3u32);
+    assert_eq!(path.states, vec![0, 1, 2, 3]);
+  }
+
+  #[test]
+  fn test_astar_matches_dijkstra() {
+    let maze = maze();
+    let goal = Coord::new(6, 3);
+    let successors = |c: &Coord| {
+      open_neighbors(&maze, *c)
+        .into_iter()
+        .map(|next| (next, 1 + next.x))
+    };
+    let heuristic = |c: &Coord| goal.x.abs_diff(c.x) + goal.y.abs_diff(c.y);
+
+    let guided = astar(Coord::new(0, 0), successors, heuristic, |&c| c == goal).unwrap();
+    let plain = dijkstra(Coord::new(0, 0), successors, |&c| c == goal).unwrap();
+    assert_eq!(guided.cost, plain.cost);
+    assert_eq!(guided.states.last(), Some(&goal));
+  }
+}
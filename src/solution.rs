@@ -0,0 +1,127 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::{Answer, ParseError};
+
+/// One of the two puzzles of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+  One,
+  Two,
+}
+
+impl Part {
+  pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+  pub fn number(self) -> u8 {
+    match self {
+      Part::One => 1,
+      Part::Two => 2,
+    }
+  }
+}
+
+impl Display for Part {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.number())
+  }
+}
+
+impl FromStr for Part {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim() {
+      "1" => Ok(Part::One),
+      "2" => Ok(Part::Two),
+      _ => Err(format!("Invalid part number: {}", s)),
+    }
+  }
+}
+
+/// A day's puzzle: the input is parsed once into `Self`, then both parts are answered from it.
+pub trait Solution: Sized + 'static {
+  /// Day of December, 1 through 25.
+  const DAY: u8;
+  /// Puzzle title as shown on the calendar.
+  const TITLE: &'static str;
+
+  fn parse(input: &str) -> Result<Self, ParseError>;
+  fn part1(&self) -> Answer;
+  fn part2(&self) -> Answer;
+
+  /// Overrides a parameter that the puzzle text fixes for the real input but changes for its
+  /// examples, such as day 21's step count.
+  fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+    let _ = value;
+    Err(format!("Day {} has no parameter {}", Self::DAY, name))
+  }
+
+  fn solve(&self, part: Part) -> Answer {
+    match part {
+      Part::One => self.part1(),
+      Part::Two => self.part2(),
+    }
+  }
+}
+
+/// A parsed puzzle with its concrete type erased, as handed out by [`Day::parse`].
+pub trait Puzzle {
+  fn solve(&self, part: Part) -> Answer;
+  fn set_param(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+struct Parsed<S>(S);
+
+impl<S: Solution> Puzzle for Parsed<S> {
+  fn solve(&self, part: Part) -> Answer {
+    self.0.solve(part)
+  }
+
+  fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+    self.0.set_param(name, value)
+  }
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Result<Box<dyn Puzzle>, ParseError> {
+  match S::parse(input) {
+    Ok(parsed) => Ok(Box::new(Parsed(parsed))),
+    Err(e) => Err(e.in_day(S::DAY)),
+  }
+}
+
+/// Registry entry for one day, usable without knowing its concrete [`Solution`] type.
+#[derive(Clone, Copy)]
+pub struct Day {
+  pub number: u8,
+  pub title: &'static str,
+  parse: fn(&str) -> Result<Box<dyn Puzzle>, ParseError>,
+}
+
+impl Day {
+  pub const fn of<S: Solution>() -> Self {
+    Day {
+      number: S::DAY,
+      title: S::TITLE,
+      parse: parse_erased::<S>,
+    }
+  }
+
+  /// Parses `input`; errors carry this day's number.
+  pub fn parse(&self, input: &str) -> Result<Box<dyn Puzzle>, ParseError> {
+    (self.parse)(input)
+  }
+
+  pub fn solve(&self, input: &str, part: Part) -> Result<Answer, ParseError> {
+    Ok(self.parse(input)?.solve(part))
+  }
+}
+
+impl fmt::Debug for Day {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Day")
+      .field("number", &self.number)
+      .field("title", &self.title)
+      .finish()
+  }
+}
@@ -0,0 +1,240 @@
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::answers::KnownAnswers;
+use crate::budget::{self, Budget};
+use crate::input::InputSource;
+use crate::memory::Usage;
+use crate::random;
+use crate::report::input_hash;
+use crate::{Answer, Part, days};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+  Pass,
+  Fail {
+    expected: Answer,
+    actual: Answer,
+  },
+  /// No known answer is recorded, so the solution was not run.
+  Missing,
+  /// The input could not be read, or the solution panicked.
+  Error(String),
+}
+
+impl Display for Status {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Status::Pass => write!(f, "pass"),
+      Status::Fail { .. } => write!(f, "fail"),
+      Status::Missing => write!(f, "missing"),
+      Status::Error(_) => write!(f, "error"),
+    }
+  }
+}
+
+/// Outcome of checking one day, part and input against the known answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+  pub day: u8,
+  pub part: Part,
+  pub input: String,
+  /// [`input_hash`] of the input, if it was read.
+  pub hash: Option<u64>,
+  /// Seed of the [`random`](crate::random) choices the solution made, if it ran with one.
+  pub seed: Option<u64>,
+  /// The answer the solution gave, if it ran to the end.
+  pub answer: Option<Answer>,
+  /// Time spent solving the part, not counting parsing.
+  pub time: Option<Duration>,
+  /// What solving took from the allocator, if it was measured.
+  pub memory: Option<Usage>,
+  pub status: Status,
+}
+
+impl Status {
+  /// Status of `actual` against the `expected` answer, if one is known.
+  pub fn of(expected: Option<&Answer>, actual: &Answer) -> Status {
+    match expected {
+      None => Status::Missing,
+      Some(expected) if expected == actual => Status::Pass,
+      Some(expected) => Status::Fail {
+        expected: expected.clone(),
+        actual: actual.clone(),
+      },
+    }
+  }
+}
+
+/// Checks every input of `days` that either has a known answer or exists in `dir`.
+///
+/// Solutions only run where an answer is known, with the input's parameters applied first, and
+/// each part within `budget`; panics and parts that give up are reported as [`Status::Error`].
+pub fn verify(known: &KnownAnswers, days: &[u8], dir: &Path, budget: Budget) -> Vec<Check> {
+  let mut checks = Vec::new();
+
+  for &number in days {
+    let Some(day) = days::get(number) else {
+      continue;
+    };
+    let mut inputs: BTreeSet<String> = discover_inputs(dir, number).into_iter().collect();
+    inputs.extend(known.inputs(number).map(String::from));
+
+    for input in inputs {
+      let expected: Vec<(Part, Option<&Answer>)> = Part::BOTH
+        .iter()
+        .map(|&part| (part, known.get(number, part, &input)))
+        .collect();
+
+      let mut hash = None;
+      let puzzle = if expected.iter().any(|(_, answer)| answer.is_some()) {
+        InputSource::Path(PathBuf::from(&input))
+          .load_from(number, dir)
+          .map_err(|e| e.to_string())
+          .and_then(|loaded| {
+            hash = Some(input_hash(&loaded.text));
+            catch_panic(|| day.parse(&loaded.text))
+          })
+          .and_then(|parsed| parsed.map_err(|e| e.to_string()))
+          .and_then(|mut puzzle| {
+            for (name, value) in known.params(number, &input) {
+              puzzle.set_param(name, value)?;
+            }
+            Ok(puzzle)
+          })
+      } else {
+        Err(String::new())
+      };
+
+      for (part, expected) in expected {
+        let (answer, time, status) = match (expected, &puzzle) {
+          (None, _) => (None, None, Status::Missing),
+          (Some(_), Err(e)) => (None, None, Status::Error(e.clone())),
+          (Some(expected), Ok(puzzle)) => {
+            let start = Instant::now();
+            match catch_panic(|| budget::run(budget, || puzzle.solve(part))) {
+              Ok(Ok(actual)) => {
+                let status = Status::of(Some(expected), &actual);
+                (Some(actual), Some(start.elapsed()), status)
+              }
+              Ok(Err(gave_up)) => (None, None, Status::Error(gave_up.to_string())),
+              Err(e) => (None, None, Status::Error(e)),
+            }
+          }
+        };
+        checks.push(Check {
+          day: number,
+          part,
+          input: input.clone(),
+          hash,
+          seed: random::seed(),
+          answer,
+          time,
+          memory: None,
+          status,
+        });
+      }
+    }
+  }
+
+  checks
+}
+
+/// Names of the `dNN_<name>.txt` files for `day` in `dir`.
+pub fn discover_inputs(dir: &Path, day: u8) -> Vec<String> {
+  let prefix = format!("d{:02}_", day);
+  let Ok(entries) = fs::read_dir(dir) else {
+    return Vec::new();
+  };
+
+  let mut names: Vec<String> = entries
+    .filter_map(|entry| entry.ok())
+    .filter_map(|entry| {
+      let file_name = entry.file_name().into_string().ok()?;
+      let name = file_name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+      Some(name.to_string())
+    })
+    .collect();
+  names.sort();
+  names
+}
+
+/// Runs `f`, turning a panic into its message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+  panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    message.to_string()
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    message.clone()
+  } else {
+    "panicked".to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::input::DEFAULT_INPUT_DIR;
+
+  #[test]
+  fn test_verify_reports_each_status() {
+    let known = KnownAnswers::parse("2 1 simple 8\n2 2 simple 9\n2 1 missing 1\n").unwrap();
+    let checks = verify(
+      &known,
+      &[2],
+      Path::new(DEFAULT_INPUT_DIR),
+      Budget::UNLIMITED,
+    );
+    let status = |input: &str, part| {
+      checks
+        .iter()
+        .find(|check| check.input == input && check.part == part)
+        .map(|check| &check.status)
+        .unwrap()
+    };
+
+    assert_eq!(status("simple", Part::One), &Status::Pass);
+    assert_eq!(
+      status("simple", Part::Two),
+      &Status::Fail {
+        expected: Answer::from(9),
+        actual: Answer::from(2286),
+      }
+    );
+    assert_eq!(status("full", Part::One), &Status::Missing);
+    assert!(matches!(status("missing", Part::One), Status::Error(_)));
+    assert_eq!(status("missing", Part::Two), &Status::Missing);
+
+    let pass = checks
+      .iter()
+      .find(|check| check.input == "simple" && check.part == Part::One)
+      .unwrap();
+    assert_eq!(pass.answer, Some(Answer::from(8)));
+    assert!(pass.hash.is_some() && pass.time.is_some());
+  }
+
+  #[test]
+  fn test_discover_inputs() {
+    assert_eq!(
+      discover_inputs(Path::new(DEFAULT_INPUT_DIR), 8),
+      vec!["full", "simple1", "simple2", "simple3"]
+    );
+  }
+
+  #[test]
+  fn test_catch_panic() {
+    assert_eq!(catch_panic(|| 1), Ok(1));
+    assert_eq!(
+      catch_panic(|| -> u8 { panic!("boom {}", 1) }),
+      Err("boom 1".to_string())
+    );
+  }
+}